@@ -0,0 +1,183 @@
+//! [Compact Log Event Format](https://clef-json.org/) support, for Serilog and Seq
+
+use std::fmt;
+
+use crate::VerbosityFilter;
+
+/// A Serilog `LogEventLevel`, as written to CLEF's `@l`
+///
+/// Serilog's `Fatal` has no counterpart, and [`VerbosityFilter::Off`] converts to `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Verbose,
+    Debug,
+    Information,
+    Warning,
+    Error,
+}
+
+impl Level {
+    /// The name Serilog uses for the level
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Verbose => "Verbose",
+            Self::Debug => "Debug",
+            Self::Information => "Information",
+            Self::Warning => "Warning",
+            Self::Error => "Error",
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<VerbosityFilter> for Option<Level> {
+    fn from(filter: VerbosityFilter) -> Self {
+        match filter {
+            VerbosityFilter::Off => None,
+            VerbosityFilter::Error => Some(Level::Error),
+            VerbosityFilter::Warn => Some(Level::Warning),
+            VerbosityFilter::Info => Some(Level::Information),
+            VerbosityFilter::Debug => Some(Level::Debug),
+            VerbosityFilter::Trace => Some(Level::Verbose),
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+impl From<log::Level> for Level {
+    fn from(level: log::Level) -> Self {
+        match level {
+            log::Level::Error => Self::Error,
+            log::Level::Warn => Self::Warning,
+            log::Level::Info => Self::Information,
+            log::Level::Debug => Self::Debug,
+            log::Level::Trace => Self::Verbose,
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl From<tracing_core::Level> for Level {
+    fn from(level: tracing_core::Level) -> Self {
+        match level {
+            tracing_core::Level::ERROR => Self::Error,
+            tracing_core::Level::WARN => Self::Warning,
+            tracing_core::Level::INFO => Self::Information,
+            tracing_core::Level::DEBUG => Self::Debug,
+            tracing_core::Level::TRACE => Self::Verbose,
+        }
+    }
+}
+
+/// A [`tracing_subscriber`] event format writing CLEF JSON lines
+///
+/// Each event becomes an object with `@t`, `@l`, `@m`, `SourceContext` and the event's fields:
+/// ```rust,no_run
+/// # use clap::Parser;
+/// # use clap_verbosity_flag::Verbosity;
+/// # use clap_verbosity_flag::clef::JsonFormat;
+/// #
+/// # /// Le CLI
+/// # #[derive(Debug, Parser)]
+/// # struct Cli {
+/// #     #[command(flatten)]
+/// #     verbose: Verbosity,
+/// # }
+/// let cli = Cli::parse();
+/// tracing_subscriber::fmt()
+///     .with_max_level(cli.verbose)
+///     .event_format(JsonFormat)
+///     .init();
+/// ```
+#[cfg(feature = "tracing-subscriber")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonFormat;
+
+#[cfg(feature = "tracing-subscriber")]
+impl<S, N> tracing_subscriber::fmt::FormatEvent<S, N> for JsonFormat
+where
+    S: tracing_core::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    N: for<'a> tracing_subscriber::fmt::FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        _ctx: &tracing_subscriber::fmt::FmtContext<'_, S, N>,
+        mut writer: tracing_subscriber::fmt::format::Writer<'_>,
+        event: &tracing_core::Event<'_>,
+    ) -> fmt::Result {
+        use tracing_subscriber::fmt::time::FormatTime as _;
+
+        let mut timestamp = String::new();
+        tracing_subscriber::fmt::time::SystemTime.format_time(
+            &mut tracing_subscriber::fmt::format::Writer::new(&mut timestamp),
+        )?;
+
+        let metadata = event.metadata();
+        let mut line = crate::json::JsonLine::new();
+        line.str("@t", &timestamp);
+        line.str("@l", Level::from(*metadata.level()).as_str());
+        line.fields(event, "@m");
+        line.str("SourceContext", metadata.target());
+        writeln!(writer, "{}", line.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_filter() {
+        assert_eq!(Option::<Level>::from(VerbosityFilter::Off), None);
+        assert_eq!(
+            Option::<Level>::from(VerbosityFilter::Error),
+            Some(Level::Error)
+        );
+        assert_eq!(
+            Option::<Level>::from(VerbosityFilter::Warn),
+            Some(Level::Warning)
+        );
+        assert_eq!(
+            Option::<Level>::from(VerbosityFilter::Info),
+            Some(Level::Information)
+        );
+        assert_eq!(
+            Option::<Level>::from(VerbosityFilter::Debug),
+            Some(Level::Debug)
+        );
+        assert_eq!(
+            Option::<Level>::from(VerbosityFilter::Trace),
+            Some(Level::Verbose)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tracing-subscriber")]
+    fn json_format() {
+        let buffer = crate::tracing_subscriber::TestWriter::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing_core::LevelFilter::INFO)
+            .event_format(JsonFormat)
+            .with_writer(buffer.clone())
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(code = 7, "engines exist");
+            tracing::debug!("hidden");
+        });
+
+        let contents = buffer.contents();
+        assert!(contents.starts_with("{\"@t\":\""), "{contents}");
+        assert!(
+            contents.ends_with(
+                "\",\"@l\":\"Information\",\"@m\":\"engines exist\",\"code\":7,\"SourceContext\":\"clap_verbosity_flag::clef::tests\"}\n"
+            ),
+            "{contents}"
+        );
+    }
+}
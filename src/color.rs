@@ -0,0 +1,199 @@
+use std::ffi::OsString;
+use std::io;
+
+use clap::parser::ValueSource;
+
+/// Whether to color the output
+///
+/// This is separate from [`clap::ColorChoice`], which only covers clap's own help and errors.
+/// When glob importing both crates, name this one explicitly, e.g.
+/// `use clap_verbosity_flag::ColorChoice as LogColor;`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ColorChoice {
+    /// Color when writing to a terminal, following `NO_COLOR` and `CLICOLOR_FORCE`
+    #[default]
+    Auto,
+    /// Always color, even when not writing to a terminal
+    Always,
+    /// Never color
+    Never,
+}
+
+impl ColorChoice {
+    /// Whether to color output going to `stream`
+    ///
+    /// With [`ColorChoice::Auto`], a non-empty [`NO_COLOR`](https://no-color.org/) disables
+    /// colors and a [`CLICOLOR_FORCE`](https://bixense.com/clicolors/) other than `0` enables them
+    /// even when `stream` isn't a terminal.
+    pub fn should_color(&self, stream: &impl io::IsTerminal) -> bool {
+        match self {
+            Self::Auto => auto_color(
+                stream.is_terminal(),
+                std::env::var_os("NO_COLOR"),
+                std::env::var_os("CLICOLOR_FORCE"),
+            ),
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
+
+/// [`ColorChoice::Auto`], with the environment passed in
+fn auto_color(is_terminal: bool, no_color: Option<OsString>, force: Option<OsString>) -> bool {
+    if force.map(|force| force != "0").unwrap_or(false) {
+        true
+    } else if no_color
+        .map(|no_color| !no_color.is_empty())
+        .unwrap_or(false)
+    {
+        false
+    } else {
+        is_terminal
+    }
+}
+
+/// `--color` option to `#[command(flatten)]` into your CLI, next to [`Verbosity`][crate::Verbosity]
+///
+/// ```rust,no_run
+/// # use clap::Parser;
+/// # use clap_verbosity_flag::{ColorArg, Verbosity};
+/// #
+/// # /// Le CLI
+/// # #[derive(Debug, Parser)]
+/// # struct Cli {
+/// #     #[command(flatten)]
+/// #     verbose: Verbosity,
+/// #     #[command(flatten)]
+/// #     color: ColorArg,
+/// # }
+/// let cli = Cli::parse();
+/// let color = cli.color.should_color(&std::io::stderr());
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ColorArg {
+    choice: ColorChoice,
+    source: Option<ValueSource>,
+}
+
+impl ColorArg {
+    /// Create a new instance by explicitly setting the choice
+    pub fn new(choice: ColorChoice) -> Self {
+        Self {
+            choice,
+            source: None,
+        }
+    }
+
+    /// The requested choice, [`ColorChoice::Auto`] when `--color` is absent
+    pub fn choice(&self) -> ColorChoice {
+        self.choice
+    }
+
+    /// Whether `--color` was passed on the command line
+    pub fn is_present(&self) -> bool {
+        self.source == Some(ValueSource::CommandLine)
+    }
+
+    /// Whether to color output going to `stream`, see [`ColorChoice::should_color`]
+    pub fn should_color(&self, stream: &impl io::IsTerminal) -> bool {
+        self.choice.should_color(stream)
+    }
+}
+
+impl From<ColorChoice> for ColorArg {
+    fn from(choice: ColorChoice) -> Self {
+        Self::new(choice)
+    }
+}
+
+impl clap::FromArgMatches for ColorArg {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut color = Self::default();
+        color.update_from_arg_matches(matches)?;
+        Ok(color)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        if let Some(choice) = matches.get_one::<ColorChoice>(COLOR_ID) {
+            self.choice = *choice;
+            self.source = matches.value_source(COLOR_ID);
+        }
+        Ok(())
+    }
+}
+
+impl clap::Args for ColorArg {
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        cmd.arg(
+            clap::Arg::new(COLOR_ID)
+                .long(COLOR_ID)
+                .value_name("WHEN")
+                .value_parser(clap::value_parser!(ColorChoice))
+                .global(true)
+                .help("When to use colors"),
+        )
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+const COLOR_ID: &str = "color";
+
+#[cfg(test)]
+mod tests {
+    use clap::{CommandFactory as _, Parser};
+
+    use super::*;
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(flatten)]
+        color: ColorArg,
+        #[command(flatten)]
+        verbose: crate::Verbosity,
+    }
+
+    #[test]
+    fn verify_app() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn parse() {
+        let cli = Cli::try_parse_from(["cmd"]).unwrap();
+        assert_eq!(cli.color.choice(), ColorChoice::Auto);
+        assert!(!cli.color.is_present());
+
+        let cli = Cli::try_parse_from(["cmd", "--color", "never", "-v"]).unwrap();
+        assert_eq!(cli.color.choice(), ColorChoice::Never);
+        assert!(cli.color.is_present());
+        assert!(!cli.color.should_color(&io::stderr()));
+        assert!(Cli::try_parse_from(["cmd", "--color", "rainbow"]).is_err());
+    }
+
+    #[test]
+    fn help() {
+        let help = Cli::command().render_long_help().to_string();
+        assert!(help.contains("always: Always color"), "{help}");
+        assert!(help.contains("never:  Never color"), "{help}");
+    }
+
+    #[test]
+    fn auto() {
+        assert!(auto_color(true, None, None));
+        assert!(!auto_color(false, None, None));
+        assert!(!auto_color(true, Some("1".into()), None));
+        assert!(auto_color(true, Some("".into()), None));
+        assert!(auto_color(false, None, Some("1".into())));
+        assert!(!auto_color(false, None, Some("0".into())));
+        assert!(auto_color(false, Some("1".into()), Some("1".into())));
+    }
+
+    #[test]
+    fn always_never() {
+        assert!(ColorChoice::Always.should_color(&io::stderr()));
+        assert!(!ColorChoice::Never.should_color(&io::stderr()));
+    }
+}
@@ -0,0 +1,93 @@
+//! Scale [`color_eyre`] report detail with the flags
+
+use std::env;
+
+use color_eyre::config::HookBuilder;
+
+use crate::{LogLevel, Verbosity};
+
+impl<L: LogLevel> Verbosity<L> {
+    /// A [`HookBuilder`] showing more of each report the more `--verbose` is passed
+    ///
+    /// - By default, reports only show the error chain
+    /// - From `-vv`, reports also capture a span trace and show the environment section
+    ///
+    /// Span traces need a `tracing_error::ErrorLayer` in the subscriber.
+    pub fn eyre_hook_builder(&self) -> HookBuilder {
+        let detailed = self.offset() >= 2;
+        HookBuilder::default()
+            .capture_span_trace_by_default(detailed)
+            .display_env_section(detailed)
+    }
+
+    /// Install [`Verbosity::eyre_hook_builder`] as the panic and error report hooks
+    ///
+    /// At debug and trace, backtraces are captured too, by setting `RUST_LIB_BACKTRACE` to
+    /// [`Verbosity::backtrace_env`] unless the environment already sets it.  Call this at the start of `main`, before any
+    /// other thread is spawned.
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// fn main() -> color_eyre::Result<()> {
+    ///     let cli = Cli::parse();
+    ///     cli.verbose.install_eyre_hooks()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn install_eyre_hooks(&self) -> color_eyre::Result<()> {
+        if let Some(backtrace) = self.backtrace_env() {
+            if env::var_os("RUST_LIB_BACKTRACE").is_none() {
+                env::set_var("RUST_LIB_BACKTRACE", backtrace);
+            }
+        }
+        self.eyre_hook_builder().install()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use color_eyre::eyre::EyreHandler;
+
+    use super::*;
+    use crate::ErrorLevel;
+
+    #[derive(Debug)]
+    struct Failed;
+
+    impl fmt::Display for Failed {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("failed")
+        }
+    }
+
+    impl std::error::Error for Failed {}
+
+    struct Render(Box<dyn EyreHandler>);
+
+    impl fmt::Display for Render {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.debug(&Failed, f)
+        }
+    }
+
+    #[test]
+    fn eyre_hook_builder() {
+        // `color_spantrace` only lets the hooks be built once per process
+        let (_, hook) = Verbosity::<ErrorLevel>::new(2, 0)
+            .eyre_hook_builder()
+            .into_hooks();
+        let report = Render(hook.into_eyre_hook()(&Failed)).to_string();
+        assert!(report.contains("failed"), "{report}");
+        assert!(report.contains("environment variable"), "{report}");
+    }
+}
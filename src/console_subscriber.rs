@@ -0,0 +1,73 @@
+//! Debug async runtimes with [tokio-console](https://github.com/tokio-rs/console) at high
+//! verbosity
+
+use std::io;
+
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+use crate::{LogLevel, Verbosity, VerbosityFilter};
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Spawn the [`console_subscriber`] server if the flags reach `threshold`
+    ///
+    /// The console only sees tasks when built with `RUSTFLAGS="--cfg tokio_unstable"`.  Its layer
+    /// needs the runtime's trace-level spans, so filter other layers individually rather than with
+    /// a global max level, like [`Verbosity::try_init_tracing_with_console`] does.
+    pub fn console_layer<S>(&self, threshold: VerbosityFilter) -> Option<impl Layer<S>>
+    where
+        S: tracing_core::Subscriber + for<'a> LookupSpan<'a>,
+    {
+        (self.filter() >= threshold).then(console_subscriber::spawn::<S>)
+    }
+
+    /// Install a stderr [`tracing_subscriber::fmt()`] layer at the flags' level, with
+    /// [`Verbosity::console_layer`] underneath
+    ///
+    /// e.g. to start the console server on `-vvvv`:
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::{Verbosity, VerbosityFilter};
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose
+    ///     .try_init_tracing_with_console(VerbosityFilter::Trace)
+    ///     .unwrap();
+    /// ```
+    pub fn try_init_tracing_with_console(
+        &self,
+        threshold: VerbosityFilter,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let fmt = tracing_subscriber::fmt::layer()
+            .with_writer(io::stderr)
+            .with_filter(self.tracing_level_filter());
+        tracing_subscriber::registry()
+            .with(self.console_layer(threshold))
+            .with(fmt)
+            .try_init()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing_subscriber::Registry;
+
+    use super::*;
+    use crate::InfoLevel;
+
+    #[test]
+    fn below_threshold() {
+        let verbosity = Verbosity::<InfoLevel>::new(1, 0);
+        assert!(verbosity
+            .console_layer::<Registry>(VerbosityFilter::Trace)
+            .is_none());
+    }
+}
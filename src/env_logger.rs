@@ -0,0 +1,210 @@
+//! Set up [`env_logger`] from the flags
+
+use std::io::Write as _;
+
+use env_logger::{Builder, Env, Target, WriteStyle};
+
+#[cfg(feature = "timestamps")]
+use crate::Timestamps;
+use crate::{ColorChoice, LogLevel, Verbosity, VerbosityFilter};
+
+impl<L: LogLevel> Verbosity<L> {
+    /// An [`env_logger::Builder`] writing `level: message` lines to stderr at the flags' level
+    ///
+    /// Labels are colored with [`LogLevel::level_styles`] when stderr supports it.  Customize the
+    /// builder further before calling [`Builder::init`], or use [`Verbosity::init_env_logger`].
+    pub fn env_logger_builder(&self) -> Builder {
+        let styles = L::level_styles();
+        let mut builder = Builder::new();
+        builder
+            .filter_level(self.log_level_filter())
+            .target(Target::Stderr)
+            .format(move |buf, record| {
+                let level = VerbosityFilter::from(record.level());
+                let style = styles.get(level);
+                writeln!(buf, "{style}{level}{style:#}: {}", record.args())
+            });
+        builder
+    }
+
+    /// [`Verbosity::env_logger_builder`], prefixing each line with the time in `timestamps`
+    #[cfg(feature = "timestamps")]
+    pub fn env_logger_builder_with_timestamps(&self, timestamps: Timestamps) -> Builder {
+        let styles = L::level_styles();
+        let mut builder = self.env_logger_builder();
+        builder.format(move |buf, record| {
+            let level = VerbosityFilter::from(record.level());
+            let style = styles.get(level);
+            if let Some(now) = timestamps.now() {
+                write!(buf, "{now} ")?;
+            }
+            writeln!(buf, "{style}{level}{style:#}: {}", record.args())
+        });
+        builder
+    }
+
+    /// [`Verbosity::env_logger_builder`], with directives from the environment like `RUST_LOG`
+    ///
+    /// Per-module directives, like `RUST_LOG=hyper=warn`, always apply.  A level without a module,
+    /// like `RUST_LOG=debug`, replaces the flags' level only when no flags were passed, see
+    /// [`Verbosity::is_present`].  So `-v` and `-q` always control the baseline, even with
+    /// `RUST_LOG` exported in the shell:
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose
+    ///     .env_logger_builder_from_env(env_logger::Env::default())
+    ///     .init();
+    /// ```
+    pub fn env_logger_builder_from_env<'e>(&self, env: impl Into<Env<'e>>) -> Builder {
+        let mut builder = self.env_logger_builder();
+        builder.parse_env(env);
+        if self.is_present() {
+            builder.filter_level(self.log_level_filter());
+        }
+        builder
+    }
+
+    /// Install [`Verbosity::env_logger_builder`] as the global logger
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose.init_env_logger();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If a global logger was already installed, see [`Verbosity::try_init_env_logger`].
+    pub fn init_env_logger(&self) {
+        self.env_logger_builder().init();
+    }
+
+    /// Install [`Verbosity::env_logger_builder`] as the global logger, failing if one already is
+    pub fn try_init_env_logger(&self) -> Result<(), log::SetLoggerError> {
+        self.env_logger_builder().try_init()
+    }
+}
+
+/// e.g. `builder.write_style(cli.color.choice().into())` with a [`ColorArg`][crate::ColorArg]
+impl From<ColorChoice> for WriteStyle {
+    fn from(choice: ColorChoice) -> Self {
+        match choice {
+            ColorChoice::Auto => Self::Auto,
+            ColorChoice::Always => Self::Always,
+            ColorChoice::Never => Self::Never,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::sync::{Arc, Mutex};
+
+    use log::Log as _;
+
+    use super::*;
+    use crate::ErrorLevel;
+
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn env_logger_builder() {
+        let capture = Capture::default();
+        let logger = Verbosity::<ErrorLevel>::new(1, 0)
+            .env_logger_builder()
+            .target(Target::Pipe(Box::new(capture.clone())))
+            .write_style(WriteStyle::Never)
+            .build();
+        assert_eq!(logger.filter(), log::LevelFilter::Warn);
+
+        for (level, message) in [(log::Level::Warn, "shown"), (log::Level::Info, "hidden")] {
+            logger.log(
+                &log::Record::builder()
+                    .level(level)
+                    .args(format_args!("{message}"))
+                    .build(),
+            );
+        }
+        let output = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(output, "warn: shown\n");
+    }
+
+    #[test]
+    #[cfg(feature = "timestamps")]
+    fn env_logger_builder_with_timestamps() {
+        let capture = Capture::default();
+        let logger = Verbosity::<ErrorLevel>::new(0, 0)
+            .env_logger_builder_with_timestamps(Timestamps::Seconds)
+            .target(Target::Pipe(Box::new(capture.clone())))
+            .write_style(WriteStyle::Never)
+            .build();
+        logger.log(
+            &log::Record::builder()
+                .level(log::Level::Error)
+                .args(format_args!("failed"))
+                .build(),
+        );
+        let output = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        let (time, line) = output.split_once(' ').unwrap();
+        assert_eq!(time.len(), "2024-05-01T12:00:00Z".len(), "{output}");
+        assert!(time.ends_with('Z'), "{output}");
+        assert_eq!(line, "error: failed\n");
+    }
+
+    #[test]
+    fn env_logger_builder_from_env() {
+        let enabled = |logger: &env_logger::Logger, target: &str, level: log::Level| {
+            logger.enabled(&log::Metadata::builder().target(target).level(level).build())
+        };
+        let env = || Env::new().filter("CLAP_VERBOSITY_FLAG_TEST_LOG");
+        std::env::set_var("CLAP_VERBOSITY_FLAG_TEST_LOG", "debug,hyper=trace");
+
+        let logger = Verbosity::<ErrorLevel>::new(0, 0)
+            .env_logger_builder_from_env(env())
+            .build();
+        assert!(enabled(&logger, "app", log::Level::Debug));
+        assert!(enabled(&logger, "hyper", log::Level::Trace));
+
+        let logger = Verbosity::<ErrorLevel>::new(1, 0)
+            .env_logger_builder_from_env(env())
+            .build();
+        assert!(enabled(&logger, "app", log::Level::Warn));
+        assert!(!enabled(&logger, "app", log::Level::Info));
+        assert!(enabled(&logger, "hyper", log::Level::Trace));
+
+        std::env::remove_var("CLAP_VERBOSITY_FLAG_TEST_LOG");
+        let logger = Verbosity::<ErrorLevel>::new(0, 0)
+            .env_logger_builder_from_env(env())
+            .build();
+        assert_eq!(logger.filter(), log::LevelFilter::Error);
+    }
+}
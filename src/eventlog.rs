@@ -0,0 +1,113 @@
+//! [Windows Event Log](https://learn.microsoft.com/en-us/windows/win32/eventlog/event-types)
+//! support
+
+use std::fmt;
+
+use crate::VerbosityFilter;
+
+/// A Windows Event Log event type
+///
+/// The event log has no debug or trace types, so [`VerbosityFilter::Debug`] and
+/// [`VerbosityFilter::Trace`] map to [`EventType::Information`], and [`VerbosityFilter::Off`] maps
+/// to [`EventType::Error`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventType {
+    Error,
+    Warning,
+    Information,
+}
+
+impl EventType {
+    /// The `EVENTLOG_*_TYPE` value passed to `ReportEvent`
+    pub fn code(&self) -> u16 {
+        match self {
+            Self::Error => 0x0001,
+            Self::Warning => 0x0002,
+            Self::Information => 0x0004,
+        }
+    }
+
+    /// The name shown in the Event Viewer's level column
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "Error",
+            Self::Warning => "Warning",
+            Self::Information => "Information",
+        }
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<VerbosityFilter> for EventType {
+    fn from(filter: VerbosityFilter) -> Self {
+        match filter {
+            VerbosityFilter::Off | VerbosityFilter::Error => Self::Error,
+            VerbosityFilter::Warn => Self::Warning,
+            VerbosityFilter::Info | VerbosityFilter::Debug | VerbosityFilter::Trace => {
+                Self::Information
+            }
+        }
+    }
+}
+
+impl From<log::Level> for EventType {
+    fn from(level: log::Level) -> Self {
+        VerbosityFilter::from(Some(level)).into()
+    }
+}
+
+#[cfg(windows)]
+impl<L: crate::LogLevel> crate::Verbosity<L> {
+    /// Install the [`eventlog`](https://docs.rs/eventlog) logger as the global logger at the
+    /// flags' level
+    ///
+    /// `name` is the event source, which has to be registered once, e.g. by the installer, with
+    /// `eventlog::register`.
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose.init_eventlog("My Service").unwrap();
+    /// ```
+    pub fn init_eventlog(&self, name: &str) -> Result<(), ::eventlog::InitError> {
+        // `eventlog` only takes a level, so `--quiet` down to off is applied afterwards
+        ::eventlog::init(name, self.log_level().unwrap_or(log::Level::Error))?;
+        self.apply();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_filter() {
+        assert_eq!(EventType::from(VerbosityFilter::Off), EventType::Error);
+        assert_eq!(EventType::from(VerbosityFilter::Error), EventType::Error);
+        assert_eq!(EventType::from(VerbosityFilter::Warn), EventType::Warning);
+        assert_eq!(
+            EventType::from(VerbosityFilter::Info),
+            EventType::Information
+        );
+        assert_eq!(
+            EventType::from(VerbosityFilter::Trace),
+            EventType::Information
+        );
+        assert_eq!(EventType::Warning.code(), 0x0002);
+        assert_eq!(EventType::Information.to_string(), "Information");
+    }
+}
@@ -0,0 +1,61 @@
+//! Set up [`fern`] from the flags
+
+use fern::Dispatch;
+
+use crate::{LogLevel, Verbosity};
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Set the level of `dispatch` from the flags
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose
+    ///     .apply_to_fern(fern::Dispatch::new())
+    ///     .level_for("hyper", log::LevelFilter::Warn)
+    ///     .chain(std::io::stderr())
+    ///     .apply()
+    ///     .unwrap();
+    /// ```
+    ///
+    /// Per-module levels set with [`Dispatch::level_for`] still apply.
+    pub fn apply_to_fern(&self, dispatch: Dispatch) -> Dispatch {
+        dispatch.level(self.log_level_filter())
+    }
+}
+
+/// A [`Dispatch`] to start from, at the flags' level
+impl<L: LogLevel> From<Verbosity<L>> for Dispatch {
+    fn from(verbosity: Verbosity<L>) -> Self {
+        verbosity.apply_to_fern(Dispatch::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorLevel;
+
+    #[test]
+    fn apply_to_fern() {
+        let verbosity = Verbosity::<ErrorLevel>::new(2, 0);
+        let (level, _) = verbosity
+            .apply_to_fern(Dispatch::new())
+            .chain(Box::new(std::io::sink()) as Box<dyn std::io::Write + Send>)
+            .into_log();
+        assert_eq!(level, log::LevelFilter::Info);
+
+        let (level, _) = Dispatch::from(Verbosity::<ErrorLevel>::new(0, 1))
+            .chain(Box::new(std::io::sink()) as Box<dyn std::io::Write + Send>)
+            .into_log();
+        assert_eq!(level, log::LevelFilter::Off);
+    }
+}
@@ -0,0 +1,145 @@
+//! [Google Cloud Logging](https://cloud.google.com/logging/docs/structured-logging) support
+
+use std::fmt;
+
+use crate::VerbosityFilter;
+
+/// A Google Cloud Logging [`LogSeverity`](https://cloud.google.com/logging/docs/reference/v2/rest/v2/LogEntry#logseverity)
+///
+/// Cloud Logging has no trace severity, so [`VerbosityFilter::Trace`] maps to
+/// [`Severity::Debug`], and [`VerbosityFilter::Off`] maps to [`Severity::Default`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Default,
+    Debug,
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// The name Cloud Logging expects in the `severity` field
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Default => "DEFAULT",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warning => "WARNING",
+            Self::Error => "ERROR",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<VerbosityFilter> for Severity {
+    fn from(filter: VerbosityFilter) -> Self {
+        match filter {
+            VerbosityFilter::Off => Self::Default,
+            VerbosityFilter::Error => Self::Error,
+            VerbosityFilter::Warn => Self::Warning,
+            VerbosityFilter::Info => Self::Info,
+            VerbosityFilter::Debug | VerbosityFilter::Trace => Self::Debug,
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+impl From<log::Level> for Severity {
+    fn from(level: log::Level) -> Self {
+        VerbosityFilter::from(Some(level)).into()
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl From<tracing_core::Level> for Severity {
+    fn from(level: tracing_core::Level) -> Self {
+        VerbosityFilter::from(Some(level)).into()
+    }
+}
+
+/// A [`tracing_subscriber`] event format writing Cloud Logging structured JSON lines
+///
+/// Each event becomes an object with `severity`, `message`, `target` and the event's fields:
+/// ```rust,no_run
+/// # use clap::Parser;
+/// # use clap_verbosity_flag::Verbosity;
+/// # use clap_verbosity_flag::gcp::JsonFormat;
+/// #
+/// # /// Le CLI
+/// # #[derive(Debug, Parser)]
+/// # struct Cli {
+/// #     #[command(flatten)]
+/// #     verbose: Verbosity,
+/// # }
+/// let cli = Cli::parse();
+/// tracing_subscriber::fmt()
+///     .with_max_level(cli.verbose)
+///     .event_format(JsonFormat)
+///     .init();
+/// ```
+#[cfg(feature = "tracing-subscriber")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonFormat;
+
+#[cfg(feature = "tracing-subscriber")]
+impl<S, N> tracing_subscriber::fmt::FormatEvent<S, N> for JsonFormat
+where
+    S: tracing_core::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    N: for<'a> tracing_subscriber::fmt::FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        _ctx: &tracing_subscriber::fmt::FmtContext<'_, S, N>,
+        mut writer: tracing_subscriber::fmt::format::Writer<'_>,
+        event: &tracing_core::Event<'_>,
+    ) -> fmt::Result {
+        let metadata = event.metadata();
+        let mut line = crate::json::JsonLine::new();
+        line.str("severity", Severity::from(*metadata.level()).as_str());
+        line.fields(event, "message");
+        line.str("target", metadata.target());
+        writeln!(writer, "{}", line.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_filter() {
+        assert_eq!(Severity::from(VerbosityFilter::Off), Severity::Default);
+        assert_eq!(Severity::from(VerbosityFilter::Error), Severity::Error);
+        assert_eq!(Severity::from(VerbosityFilter::Warn), Severity::Warning);
+        assert_eq!(Severity::from(VerbosityFilter::Info), Severity::Info);
+        assert_eq!(Severity::from(VerbosityFilter::Debug), Severity::Debug);
+        assert_eq!(Severity::from(VerbosityFilter::Trace), Severity::Debug);
+        assert_eq!(Severity::Warning.to_string(), "WARNING");
+    }
+
+    #[test]
+    #[cfg(feature = "tracing-subscriber")]
+    fn json_format() {
+        let buffer = crate::tracing_subscriber::TestWriter::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing_core::LevelFilter::INFO)
+            .event_format(JsonFormat)
+            .with_writer(buffer.clone())
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(code = 7, "engines \"smoking\"");
+            tracing::debug!("hidden");
+        });
+
+        assert_eq!(
+            buffer.contents(),
+            "{\"severity\":\"WARNING\",\"message\":\"engines \\\"smoking\\\"\",\"code\":7,\"target\":\"clap_verbosity_flag::gcp::tests\"}\n"
+        );
+    }
+}
@@ -0,0 +1,150 @@
+//! Keep `log` output and [`indicatif`] progress bars consistent
+
+use indicatif::{MultiProgress, ProgressDrawTarget};
+
+use crate::{LogLevel, Verbosity};
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Install `logger` as the global logger, suspending `multi` while each record is written.
+    ///
+    /// The global max level is set from [`Verbosity::log_level_filter`], so `logger` only needs to
+    /// be configured to write everything it is given.
+    pub fn init_indicatif_logger<T: log::Log + 'static>(
+        &self,
+        multi: MultiProgress,
+        logger: T,
+    ) -> Result<(), log::SetLoggerError> {
+        log::set_boxed_logger(Box::new(SuspendLogger::new(multi, logger)))?;
+        log::set_max_level(self.log_level_filter());
+        Ok(())
+    }
+
+    /// Where progress bars should draw, given the flags
+    ///
+    /// - Hidden when [`Verbosity::is_silent`]
+    /// - Stderr, refreshed twice a second, when `--quiet` lowered the level
+    /// - Stderr at indicatif's default rate otherwise
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// let bar = indicatif::ProgressBar::with_draw_target(
+    ///     Some(100),
+    ///     cli.verbose.progress_draw_target(),
+    /// );
+    /// ```
+    pub fn progress_draw_target(&self) -> ProgressDrawTarget {
+        if self.is_silent() {
+            ProgressDrawTarget::hidden()
+        } else if self.offset() < 0 {
+            ProgressDrawTarget::stderr_with_hz(2)
+        } else {
+            ProgressDrawTarget::stderr()
+        }
+    }
+
+    /// A [`MultiProgress`] drawing to [`Verbosity::progress_draw_target`], e.g. for
+    /// [`Verbosity::init_indicatif_logger`]
+    pub fn multi_progress(&self) -> MultiProgress {
+        MultiProgress::with_draw_target(self.progress_draw_target())
+    }
+}
+
+/// A [`log::Log`] that hides a [`MultiProgress`] while the wrapped logger writes a record
+///
+/// [`Verbosity::init_indicatif_logger`] installs one of these; use it directly when the logger
+/// needs to be composed with something else before being installed.
+#[derive(Debug)]
+pub struct SuspendLogger<T> {
+    multi: MultiProgress,
+    inner: T,
+}
+
+impl<T> SuspendLogger<T> {
+    /// Wrap `inner` so its output is interleaved cleanly with `multi`
+    pub fn new(multi: MultiProgress, inner: T) -> Self {
+        Self { multi, inner }
+    }
+
+    /// The progress bars being suspended
+    pub fn multi(&self) -> &MultiProgress {
+        &self.multi
+    }
+}
+
+impl<T: log::Log> log::Log for SuspendLogger<T> {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if self.inner.enabled(record.metadata()) {
+            self.multi.suspend(|| self.inner.log(record));
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use indicatif::ProgressDrawTarget;
+
+    use super::*;
+
+    /// Keeps the messages of warnings and errors
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<String>>>);
+
+    impl log::Log for Capture {
+        fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+            metadata.level() <= log::Level::Warn
+        }
+
+        fn log(&self, record: &log::Record<'_>) {
+            self.0.lock().unwrap().push(record.args().to_string());
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn suspend_logger() {
+        let capture = Capture::default();
+        let multi = MultiProgress::with_draw_target(ProgressDrawTarget::hidden());
+        let logger = SuspendLogger::new(multi, capture.clone());
+        let log = |level, message| {
+            log::Log::log(
+                &logger,
+                &log::Record::builder()
+                    .level(level)
+                    .args(format_args!("{message}"))
+                    .build(),
+            );
+        };
+
+        log(log::Level::Warn, "shown");
+        log(log::Level::Info, "hidden");
+        assert_eq!(*capture.0.lock().unwrap(), ["shown"]);
+        assert!(logger.multi().is_hidden());
+    }
+
+    #[test]
+    fn progress_draw_target() {
+        let silent = Verbosity::<crate::ErrorLevel>::new(0, 1);
+        assert!(silent.progress_draw_target().is_hidden());
+        assert!(silent.multi_progress().is_hidden());
+    }
+}
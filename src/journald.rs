@@ -0,0 +1,103 @@
+//! Log to the systemd journal with [`tracing_journald`]
+
+use std::io;
+
+pub use tracing_journald::{Layer, Priority, PriorityMappings};
+
+use tracing_core::LevelFilter;
+use tracing_subscriber::filter::Filtered;
+use tracing_subscriber::prelude::*;
+
+use crate::syslog::Severity;
+use crate::{LogLevel, Verbosity, VerbosityFilter};
+
+/// Journald priorities are syslog severities, see [`Severity`] for how levels map to them
+impl From<Severity> for Priority {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Emergency => Self::Emergency,
+            Severity::Alert => Self::Alert,
+            Severity::Critical => Self::Critical,
+            Severity::Error => Self::Error,
+            Severity::Warning => Self::Warning,
+            Severity::Notice => Self::Notice,
+            Severity::Informational => Self::Informational,
+            Severity::Debug => Self::Debug,
+        }
+    }
+}
+
+impl From<VerbosityFilter> for Priority {
+    fn from(filter: VerbosityFilter) -> Self {
+        Severity::from(filter).into()
+    }
+}
+
+/// [`PriorityMappings`] following the [`Severity`] conversions
+///
+/// Unlike [`PriorityMappings::new`], info events are [`Priority::Informational`] rather than
+/// [`Priority::Notice`], so `journalctl -p info` shows the same events as `-v` on the terminal.
+pub fn priority_mappings() -> PriorityMappings {
+    PriorityMappings {
+        error: VerbosityFilter::Error.into(),
+        warn: VerbosityFilter::Warn.into(),
+        info: VerbosityFilter::Info.into(),
+        debug: VerbosityFilter::Debug.into(),
+        trace: VerbosityFilter::Trace.into(),
+    }
+}
+
+impl<L: LogLevel> Verbosity<L> {
+    /// A journald [`Layer`] filtered at the flags' level, using [`priority_mappings`]
+    ///
+    /// Fails if the journal socket can't be reached, e.g. when not running under systemd.
+    pub fn journald_layer<S>(&self) -> io::Result<Filtered<Layer, LevelFilter, S>>
+    where
+        S: tracing_core::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    {
+        let layer = Layer::new()?.with_priority_mappings(priority_mappings());
+        Ok(layer.with_filter(self.tracing_level_filter()))
+    }
+
+    /// Install [`Verbosity::journald_layer`] as the global default subscriber
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// if cli.verbose.try_init_journald().is_err() {
+    ///     cli.verbose.init_tracing();
+    /// }
+    /// ```
+    pub fn try_init_journald(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        tracing_subscriber::registry()
+            .with(self.journald_layer()?)
+            .try_init()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_filter() {
+        assert_eq!(Priority::from(VerbosityFilter::Off), Priority::Emergency);
+        assert_eq!(Priority::from(VerbosityFilter::Error), Priority::Error);
+        assert_eq!(Priority::from(VerbosityFilter::Warn), Priority::Warning);
+        assert_eq!(
+            Priority::from(VerbosityFilter::Info),
+            Priority::Informational
+        );
+        assert_eq!(Priority::from(VerbosityFilter::Trace), Priority::Debug);
+        assert_eq!(priority_mappings().debug, Priority::Debug);
+    }
+}
@@ -0,0 +1,128 @@
+//! Minimal JSON-lines rendering for the event formatters
+
+use std::fmt::{self, Write as _};
+
+use tracing_core::field::{Field, Visit};
+use tracing_core::Event;
+
+/// A single JSON object, written one member at a time
+pub(crate) struct JsonLine {
+    buffer: String,
+}
+
+impl JsonLine {
+    pub(crate) fn new() -> Self {
+        Self {
+            buffer: String::from("{"),
+        }
+    }
+
+    pub(crate) fn str(&mut self, key: &str, value: &str) {
+        self.key(key);
+        escape(&mut self.buffer, value);
+    }
+
+    fn raw(&mut self, key: &str, value: impl fmt::Display) {
+        self.key(key);
+        let _ = write!(self.buffer, "{value}");
+    }
+
+    /// Write the event's fields, renaming `message` to `message_key`
+    pub(crate) fn fields(&mut self, event: &Event<'_>, message_key: &'static str) {
+        event.record(&mut FieldVisitor {
+            line: self,
+            message_key,
+        });
+    }
+
+    pub(crate) fn finish(mut self) -> String {
+        self.buffer.push('}');
+        self.buffer
+    }
+
+    fn key(&mut self, key: &str) {
+        if self.buffer.len() > 1 {
+            self.buffer.push(',');
+        }
+        escape(&mut self.buffer, key);
+        self.buffer.push(':');
+    }
+}
+
+struct FieldVisitor<'l> {
+    line: &'l mut JsonLine,
+    message_key: &'static str,
+}
+
+impl FieldVisitor<'_> {
+    fn key<'f>(&self, field: &'f Field) -> &'f str {
+        match field.name() {
+            "message" => self.message_key,
+            name => name,
+        }
+    }
+}
+
+impl Visit for FieldVisitor<'_> {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        if value.is_finite() {
+            self.line.raw(self.key(field), value);
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.line.raw(self.key(field), value);
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.line.raw(self.key(field), value);
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.line.raw(self.key(field), value);
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.line.str(self.key(field), value);
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.line.str(self.key(field), &format!("{value:?}"));
+    }
+}
+
+fn escape(buffer: &mut String, value: &str) {
+    buffer.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => buffer.push_str("\\\""),
+            '\\' => buffer.push_str("\\\\"),
+            '\n' => buffer.push_str("\\n"),
+            '\r' => buffer.push_str("\\r"),
+            '\t' => buffer.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(buffer, "\\u{:04x}", c as u32);
+            }
+            c => buffer.push(c),
+        }
+    }
+    buffer.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_str() {
+        let mut line = JsonLine::new();
+        line.str("message", "say \"hi\"\n\\\u{1}");
+        line.raw("count", 2);
+        assert_eq!(
+            line.finish(),
+            r#"{"message":"say \"hi\"\n\\\u0001","count":2}"#
+        );
+    }
+}
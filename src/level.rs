@@ -0,0 +1,321 @@
+use std::ffi::OsStr;
+
+use clap::builder::{PossibleValue, TypedValueParser};
+use clap::parser::ValueSource;
+
+use crate::{ErrorLevel, LogLevel, Verbosity, VerbosityFilter, VerbosityFilterParser};
+
+/// Logging level option to `#[command(flatten)]` into your CLI
+///
+/// Unlike [`Verbosity`], users name the level directly, e.g.
+/// `--verbosity debug`.  The [`LogLevel`] provides the level used when the option is absent.
+///
+/// Levels relative to that default are also accepted: `--verbosity +2` is the same as `-vv` with
+/// [`Verbosity`] and `--verbosity -1` the same as `-q`.
+#[derive(Debug, Clone, Copy)]
+pub struct VerbosityLevel<L: LogLevel = ErrorLevel> {
+    filter: VerbosityFilter,
+    source: Option<ValueSource>,
+    phantom: std::marker::PhantomData<L>,
+}
+
+impl<L: LogLevel> VerbosityLevel<L> {
+    /// The [`clap::ArgGroup`] containing the generated option
+    pub const GROUP_ID: &'static str = "clap_verbosity_level";
+
+    /// Create a new instance by explicitly setting the level
+    pub fn new(filter: VerbosityFilter) -> Self {
+        Self {
+            filter,
+            source: None,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Where the level came from.
+    ///
+    /// `None` if this was not parsed from [`clap::ArgMatches`].
+    pub fn source(&self) -> Option<ValueSource> {
+        self.source
+    }
+
+    /// Whether the level was passed on the command line
+    pub fn is_present(&self) -> bool {
+        self.source == Some(ValueSource::CommandLine)
+    }
+
+    /// Gets the filter that should be applied to the logger.
+    pub fn filter(&self) -> VerbosityFilter {
+        crate::clamp::<L>(self.filter)
+    }
+}
+
+filter_accessors!(VerbosityLevel);
+
+impl<L: LogLevel> Default for VerbosityLevel<L> {
+    fn default() -> Self {
+        Self::new(L::default_filter())
+    }
+}
+
+impl<L: LogLevel> clap::FromArgMatches for VerbosityLevel<L> {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut level = Self::default();
+        level.update_from_arg_matches(matches)?;
+        Ok(level)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        let id = crate::prefixed::<L>(LEVEL_ID);
+        if let Some(value) = matches.get_one::<LevelValue>(&id) {
+            self.filter = match *value {
+                LevelValue::Filter(filter) => filter,
+                LevelValue::Louder(verbose) => L::filter_for(verbose, 0),
+                LevelValue::Quieter(quiet) => L::filter_for(0, quiet),
+            };
+            self.source = matches.value_source(&id);
+        }
+        Ok(())
+    }
+}
+
+impl<L: LogLevel> clap::Args for VerbosityLevel<L> {
+    fn group_id() -> Option<clap::Id> {
+        Some(clap::Id::from(crate::prefixed::<L>(Self::GROUP_ID)))
+    }
+
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        let arg = clap::Arg::new(crate::prefixed::<L>(LEVEL_ID))
+            .long(crate::prefixed::<L>("verbosity"))
+            .value_name("LEVEL")
+            .value_parser(LevelValueParser {
+                filters: VerbosityFilterParser::with_filters(allowed_filters::<L>()),
+            })
+            .allow_negative_numbers(true)
+            .default_value(crate::clamp::<L>(L::default_filter()).as_str())
+            .global(L::global())
+            .help("Logging verbosity")
+            .long_help("Logging verbosity, or +N/-N levels relative to the default");
+        cmd.group(crate::flag_group::<L>(Self::GROUP_ID, &[LEVEL_ID]))
+            .arg(crate::help_placement::<L>(arg))
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+/// [`Verbosity`] plus a `--log-level <LEVEL>` option, to `#[command(flatten)]` into your CLI
+///
+/// When present, the explicit level wins over any `-v` or `-q`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VerbosityWithLevel<L: LogLevel = ErrorLevel> {
+    verbosity: Verbosity<L>,
+    level: Option<VerbosityFilter>,
+}
+
+impl<L: LogLevel> VerbosityWithLevel<L> {
+    /// The [`clap::ArgGroup`] containing `--log-level`
+    ///
+    /// `-v` and `-q` stay in [`Verbosity::GROUP_ID`].
+    pub const GROUP_ID: &'static str = "clap_verbosity_log_level";
+
+    /// Create a new instance by explicitly setting the values
+    pub fn new(verbosity: Verbosity<L>, level: Option<VerbosityFilter>) -> Self {
+        Self { verbosity, level }
+    }
+
+    /// The `-v` and `-q` counts
+    pub fn verbosity(&self) -> &Verbosity<L> {
+        &self.verbosity
+    }
+
+    /// The explicit `--log-level`, if any
+    pub fn level(&self) -> Option<VerbosityFilter> {
+        self.level
+    }
+
+    /// Whether any verbosity flags are present on the command line.
+    pub fn is_present(&self) -> bool {
+        self.level.is_some() || self.verbosity.is_present()
+    }
+
+    /// Gets the filter that should be applied to the logger.
+    pub fn filter(&self) -> VerbosityFilter {
+        match self.level {
+            Some(level) => crate::clamp::<L>(level),
+            None => self.verbosity.filter(),
+        }
+    }
+}
+
+filter_accessors!(VerbosityWithLevel);
+
+impl<L: LogLevel> clap::FromArgMatches for VerbosityWithLevel<L> {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut verbosity = Self::new(Verbosity::new(0, 0), None);
+        verbosity.update_from_arg_matches(matches)?;
+        Ok(verbosity)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        self.verbosity.update_from_arg_matches(matches)?;
+        let id = crate::prefixed::<L>(LOG_LEVEL_ID);
+        if let Some(level) = matches.get_one::<VerbosityFilter>(&id) {
+            self.level = Some(*level);
+        }
+        Ok(())
+    }
+}
+
+impl<L: LogLevel> clap::Args for VerbosityWithLevel<L> {
+    fn group_id() -> Option<clap::Id> {
+        Some(clap::Id::from(crate::prefixed::<L>(Self::GROUP_ID)))
+    }
+
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        let arg = clap::Arg::new(crate::prefixed::<L>(LOG_LEVEL_ID))
+            .long(crate::prefixed::<L>(LOG_LEVEL_ID))
+            .value_name("LEVEL")
+            .value_parser(VerbosityFilterParser::with_filters(allowed_filters::<L>()))
+            .global(L::global())
+            .help("Logging level, overriding -v and -q");
+        <Verbosity<L> as clap::Args>::augment_args(cmd)
+            .group(crate::flag_group::<L>(Self::GROUP_ID, &[LOG_LEVEL_ID]))
+            .arg(crate::help_placement::<L>(arg))
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+/// The filters within [`LogLevel::min_filter`] and [`LogLevel::max_filter`]
+fn allowed_filters<L: LogLevel>() -> impl Iterator<Item = VerbosityFilter> {
+    VerbosityFilter::iter().filter(|filter| crate::clamp::<L>(*filter) == *filter)
+}
+
+/// A parsed `--verbosity`, before resolving it against the [`LogLevel`]
+#[derive(Copy, Clone, Debug)]
+enum LevelValue {
+    Filter(VerbosityFilter),
+    Louder(u8),
+    Quieter(u8),
+}
+
+/// Parse a level, or `+N`/`-N` for a relative level
+#[derive(Clone)]
+struct LevelValueParser {
+    filters: VerbosityFilterParser,
+}
+
+impl TypedValueParser for LevelValueParser {
+    type Value = LevelValue;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        if let Some(value) = value.to_str() {
+            if let Some(Ok(count)) = value.strip_prefix('+').map(str::parse) {
+                return Ok(LevelValue::Louder(count));
+            }
+            if let Some(Ok(count)) = value.strip_prefix('-').map(str::parse) {
+                return Ok(LevelValue::Quieter(count));
+            }
+        }
+        let filter = self.filters.parse_ref(cmd, arg, value)?;
+        Ok(LevelValue::Filter(filter))
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        self.filters.possible_values()
+    }
+}
+
+const LEVEL_ID: &str = "verbosity";
+const LOG_LEVEL_ID: &str = "log-level";
+
+#[cfg(test)]
+mod tests {
+    use clap::{CommandFactory, Parser};
+
+    use super::*;
+    use crate::InfoLevel;
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(flatten)]
+        verbosity: VerbosityLevel<InfoLevel>,
+    }
+
+    #[test]
+    fn verify_app() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn parse() {
+        let cli = Cli::try_parse_from(["cmd"]).unwrap();
+        assert_eq!(cli.verbosity.filter(), VerbosityFilter::Info);
+        assert!(!cli.verbosity.is_present());
+
+        let cli = Cli::try_parse_from(["cmd", "--verbosity", "debug"]).unwrap();
+        assert_eq!(cli.verbosity.filter(), VerbosityFilter::Debug);
+        assert!(cli.verbosity.is_present());
+
+        let cli = Cli::try_parse_from(["cmd", "--verbosity=off"]).unwrap();
+        assert!(cli.verbosity.is_silent());
+
+        assert!(Cli::try_parse_from(["cmd", "--verbosity", "loud"]).is_err());
+    }
+
+    #[test]
+    fn relative() {
+        let parse = |args: &[&str]| Cli::try_parse_from(args).unwrap().verbosity.filter();
+        assert_eq!(parse(&["cmd", "--verbosity", "+1"]), VerbosityFilter::Debug);
+        assert_eq!(parse(&["cmd", "--verbosity=+9"]), VerbosityFilter::Trace);
+        assert_eq!(parse(&["cmd", "--verbosity", "-2"]), VerbosityFilter::Error);
+        assert_eq!(parse(&["cmd", "--verbosity", "-0"]), VerbosityFilter::Info);
+        assert!(Cli::try_parse_from(["cmd", "--verbosity", "+"]).is_err());
+        assert!(Cli::try_parse_from(["cmd", "--verbosity", "+-1"]).is_err());
+    }
+
+    #[test]
+    fn help() {
+        let help = Cli::command().render_help().to_string();
+        assert!(
+            help.contains("--verbosity <LEVEL>  Logging verbosity [default: info] [possible values: off, error, warn, info, debug, trace]"),
+            "{help}"
+        );
+    }
+
+    #[test]
+    fn with_level() {
+        #[derive(Debug, Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbosity: VerbosityWithLevel<InfoLevel>,
+        }
+
+        Cli::command().debug_assert();
+        let parse = |args: &[&str]| Cli::try_parse_from(args).unwrap().verbosity.filter();
+        assert_eq!(parse(&["cmd"]), VerbosityFilter::Info);
+        assert_eq!(parse(&["cmd", "-v"]), VerbosityFilter::Debug);
+        assert_eq!(
+            parse(&["cmd", "--log-level", "warn"]),
+            VerbosityFilter::Warn
+        );
+        assert_eq!(
+            parse(&["cmd", "-vv", "--log-level", "error"]),
+            VerbosityFilter::Error
+        );
+        assert!(Cli::try_parse_from(["cmd", "--log-level", "loud"]).is_err());
+
+        let cli = Cli::try_parse_from(["cmd", "--log-level", "off"]).unwrap();
+        assert!(cli.verbosity.is_silent());
+        assert_eq!(VerbosityFilter::from(cli.verbosity), VerbosityFilter::Off);
+    }
+}
@@ -56,262 +56,3041 @@
 //! }
 //! ```
 //!
-//! Or implement our [`LogLevel`] trait to customize the default log level and help output.
+//! For any other default, use [`Level`] with the filter's position, e.g. `Verbosity<Level<3>>` for
+//! info.  Or implement our [`LogLevel`] trait to customize the default log level and help output.
+//!
+//! To let users name the level directly (`--verbosity debug`), flatten [`VerbosityLevel`] instead.
+//! To offer both, with an explicit `--log-level` winning over `-v` and `-q`, flatten
+//! [`VerbosityWithLevel`].
+//! For `--debug` and `--trace` alongside `-v` and `-q`, flatten [`ShortcutVerbosity`].
+//! To only offer `-v` or `-q`, flatten [`VerboseOnly`] or [`QuietOnly`].
+//! For a log file, output format and colors on top of the flags, flatten `LogArgs` with the
+//! `log-args` feature.  For just `--color`, flatten [`ColorArg`] next to [`Verbosity`], and
+//! likewise `TimestampsArg` for `--timestamps` with the `timestamps` feature.
 
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![warn(clippy::print_stderr)]
 #![warn(clippy::print_stdout)]
 
-use std::fmt;
+use std::ffi::OsString;
+use std::fmt;
+use std::ops;
+use std::str::FromStr;
+
+use clap::builder::StyledStr;
+use clap::parser::ValueSource;
+
+#[macro_use]
+mod macros;
+
+#[cfg(feature = "clef")]
+pub mod clef;
+#[cfg(feature = "color-eyre")]
+pub mod color_eyre;
+#[cfg(feature = "console-subscriber")]
+pub mod console_subscriber;
+#[cfg(feature = "env_logger")]
+pub mod env_logger;
+#[cfg(feature = "windows-eventlog")]
+pub mod eventlog;
+#[cfg(feature = "fern")]
+pub mod fern;
+#[cfg(feature = "gcp")]
+pub mod gcp;
+#[cfg(feature = "indicatif")]
+pub mod indicatif;
+#[cfg(feature = "journald")]
+pub mod journald;
+#[cfg(feature = "log")]
+pub mod log;
+#[cfg(feature = "log4rs")]
+pub mod log4rs;
+#[cfg(feature = "miette")]
+pub mod miette;
+#[cfg(feature = "opentelemetry")]
+pub mod opentelemetry;
+#[cfg(feature = "pretty_env_logger")]
+pub mod pretty_env_logger;
+#[cfg(feature = "tracing-subscriber")]
+pub mod reload;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "simplelog")]
+pub mod simplelog;
+#[cfg(feature = "stderr-logger")]
+pub mod stderr;
+#[cfg(feature = "stderrlog")]
+pub mod stderrlog;
+#[cfg(feature = "syslog")]
+pub mod syslog;
+#[cfg(feature = "tracing")]
+pub mod tracing;
+#[cfg(feature = "tracing-appender")]
+pub mod tracing_appender;
+#[cfg(feature = "tracing-flame")]
+pub mod tracing_flame;
+#[cfg(feature = "tracing-logfmt")]
+pub mod tracing_logfmt;
+#[cfg(feature = "tracing-subscriber")]
+pub mod tracing_subscriber;
+#[cfg(feature = "tracing-tracy")]
+pub mod tracing_tracy;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+mod color;
+#[cfg(all(feature = "tracing-subscriber", any(feature = "gcp", feature = "clef")))]
+mod json;
+mod level;
+#[cfg(feature = "log-args")]
+mod log_args;
+mod only;
+mod parser;
+#[cfg(feature = "log-args")]
+mod rotate;
+mod shortcut;
+mod styles;
+#[cfg(feature = "timestamps")]
+mod timestamps;
+
+pub use color::{ColorArg, ColorChoice};
+pub use level::{VerbosityLevel, VerbosityWithLevel};
+#[cfg(feature = "log-args")]
+pub use log_args::{LogArgs, LogFormat, LogTarget};
+pub use only::{QuietOnly, VerboseOnly};
+pub use parser::VerbosityFilterParser;
+#[cfg(feature = "log-args")]
+pub use rotate::LogRotation;
+pub use shortcut::ShortcutVerbosity;
+pub use styles::LevelStyles;
+#[cfg(feature = "timestamps")]
+pub use timestamps::{Timestamps, TimestampsArg};
+
+/// Logging flags to `#[command(flatten)]` into your CLI
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Verbosity<L: LogLevel = ErrorLevel> {
+    verbose: u8,
+    quiet: u8,
+    silent: bool,
+    profile: Option<&'static str>,
+    default: Option<VerbosityFilter>,
+    verbose_source: Option<ValueSource>,
+    quiet_source: Option<ValueSource>,
+    phantom: std::marker::PhantomData<L>,
+}
+
+impl<L: LogLevel> Verbosity<L> {
+    /// The [`clap::ArgGroup`] containing the generated flags
+    ///
+    /// This is `"Verbosity"`, the id `#[derive(clap::Args)]` would give the group.  These ids are
+    /// without any [`LogLevel::prefix`].
+    ///
+    /// Use this to relate your own arguments to the flags as a whole:
+    /// ```rust
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #[derive(Debug, Parser)]
+    /// struct Cli {
+    ///     #[command(flatten)]
+    ///     verbose: Verbosity,
+    ///
+    ///     /// Machine-readable output, which can't be mixed with logging
+    ///     #[arg(long, conflicts_with = <Verbosity>::GROUP_ID)]
+    ///     json: bool,
+    /// }
+    ///
+    /// assert!(Cli::try_parse_from(["cmd", "--json", "-v"]).is_err());
+    /// ```
+    pub const GROUP_ID: &'static str = "Verbosity";
+
+    /// The [`clap::Arg`] id of `--verbose`
+    ///
+    /// Use this to relate your own arguments to the flag, e.g. with `requires`:
+    /// ```rust
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #[derive(Debug, Parser)]
+    /// struct Cli {
+    ///     #[command(flatten)]
+    ///     verbose: Verbosity,
+    ///
+    ///     /// Include timings in the log output
+    ///     #[arg(long, requires = <Verbosity>::VERBOSE_ID)]
+    ///     timings: bool,
+    /// }
+    ///
+    /// assert!(Cli::try_parse_from(["cmd", "--timings"]).is_err());
+    /// assert!(Cli::try_parse_from(["cmd", "--timings", "-v"]).is_ok());
+    /// ```
+    pub const VERBOSE_ID: &'static str = VERBOSE_ID;
+
+    /// The [`clap::Arg`] id of `--quiet`
+    pub const QUIET_ID: &'static str = QUIET_ID;
+
+    /// The [`clap::Arg`] id of the [`LogLevel::silent_long`] flag, when enabled
+    pub const SILENT_ID: &'static str = SILENT_ID;
+
+    /// Create a new verbosity instance by explicitly setting the values
+    pub const fn new(verbose: u8, quiet: u8) -> Self {
+        Verbosity {
+            verbose,
+            quiet,
+            silent: false,
+            profile: None,
+            default: None,
+            verbose_source: None,
+            quiet_source: None,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// How many times `--verbose` was passed
+    ///
+    /// Use this for behavior beyond the log level, e.g. extra diagnostics at `-vvvvv`.
+    pub const fn verbose(&self) -> u8 {
+        self.verbose
+    }
+
+    /// How many times `--quiet` was passed
+    pub const fn quiet(&self) -> u8 {
+        self.quiet
+    }
+
+    /// How many levels `--verbose` and `--quiet` move away from the default, i.e. `verbose - quiet`
+    ///
+    /// Use this to scale other behavior the same way, e.g. how many lines of context to show:
+    /// ```rust
+    /// # use clap_verbosity_flag::Verbosity;
+    /// let verbosity = <Verbosity>::new(1, 3);
+    /// let context = (3 + verbosity.offset()).max(0);
+    /// # assert_eq!(context, 1);
+    /// ```
+    pub const fn offset(&self) -> i16 {
+        self.verbose as i16 - self.quiet as i16
+    }
+
+    /// Occurrences of `--verbose` beyond those needed to reach the most verbose level
+    ///
+    /// The most verbose level is [`VerbosityFilter::Trace`] unless capped by
+    /// [`LogLevel::max_filter`].  Use this to unlock behavior past the end of the scale:
+    /// ```rust
+    /// # use clap_verbosity_flag::Verbosity;
+    /// let verbosity = <Verbosity>::new(5, 0);
+    /// if 0 < verbosity.extra_verbosity() {
+    ///     // dump raw protocol frames
+    /// }
+    /// # assert_eq!(verbosity.extra_verbosity(), 1);
+    /// ```
+    pub fn extra_verbosity(&self) -> u8 {
+        let top = self.filter_with_verbose(u8::MAX);
+        if self.silent || self.filter() != top {
+            return 0;
+        }
+        let needed = (0..=self.verbose)
+            .find(|count| self.filter_with_verbose(*count) == top)
+            .unwrap_or(self.verbose);
+        self.verbose - needed
+    }
+
+    /// Replace the `--verbose` count, e.g. with one from a config file
+    ///
+    /// [`Verbosity::verbose_source`] becomes `None`, as the count no longer came from parsing.
+    pub fn set_verbose(&mut self, verbose: u8) {
+        self.verbose = verbose;
+        self.verbose_source = None;
+    }
+
+    /// Replace the `--quiet` count
+    ///
+    /// See [`Verbosity::set_verbose`].
+    pub fn set_quiet(&mut self, quiet: u8) {
+        self.quiet = quiet;
+        self.quiet_source = None;
+    }
+
+    /// Builder-style [`Verbosity::set_verbose`]
+    pub fn with_verbose(mut self, verbose: u8) -> Self {
+        self.set_verbose(verbose);
+        self
+    }
+
+    /// Builder-style [`Verbosity::set_quiet`]
+    pub fn with_quiet(mut self, quiet: u8) -> Self {
+        self.set_quiet(quiet);
+        self
+    }
+
+    /// Replace [`LogLevel::default_filter`] with a level only known at runtime
+    ///
+    /// Use this when the baseline comes from a config file.  `-v` and `-q` then move one level at
+    /// a time from `filter`, instead of using [`LogLevel::filter_for`]:
+    /// ```rust
+    /// # use clap_verbosity_flag::{Verbosity, VerbosityFilter};
+    /// let config = VerbosityFilter::Info;
+    /// let verbosity = <Verbosity>::new(1, 0).with_default(config);
+    /// assert_eq!(verbosity.filter(), VerbosityFilter::Debug);
+    /// ```
+    ///
+    /// A `--log-profile` chosen on the command line still takes precedence.
+    pub fn set_default(&mut self, filter: VerbosityFilter) {
+        self.default = Some(filter);
+    }
+
+    /// Builder-style [`Verbosity::set_default`]
+    pub fn with_default(mut self, filter: VerbosityFilter) -> Self {
+        self.set_default(filter);
+        self
+    }
+
+    /// Where the `--verbose` count came from.
+    ///
+    /// `None` if this was not parsed from [`clap::ArgMatches`], e.g. when created with
+    /// [`Verbosity::new`].
+    ///
+    /// Unlike [`Verbosity::is_present`], this distinguishes a count the user passed on the command
+    /// line from one supplied by the argument's defaults.
+    pub fn verbose_source(&self) -> Option<ValueSource> {
+        self.verbose_source
+    }
+
+    /// Where the `--quiet` count came from.
+    ///
+    /// See [`Verbosity::verbose_source`].
+    pub fn quiet_source(&self) -> Option<ValueSource> {
+        self.quiet_source
+    }
+
+    /// Add the flags to every subcommand of `cmd`, recursively
+    ///
+    /// Required when [`LogLevel::accumulate_subcommands`] is enabled, since the flags are then no
+    /// longer `global`.  Call this on the fully-built command before parsing:
+    /// ```rust,no_run
+    /// # use clap::{CommandFactory, FromArgMatches, Parser};
+    /// # use clap_verbosity_flag::{LogLevel, Verbosity, VerbosityFilter};
+    /// #[derive(Debug)]
+    /// struct Accumulate;
+    ///
+    /// impl LogLevel for Accumulate {
+    ///     fn default_filter() -> VerbosityFilter {
+    ///         VerbosityFilter::Error
+    ///     }
+    ///
+    ///     fn accumulate_subcommands() -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug, Parser)]
+    /// struct Cli {
+    ///     #[command(flatten)]
+    ///     verbose: Verbosity<Accumulate>,
+    ///     #[command(subcommand)]
+    ///     command: Option<Command>,
+    /// }
+    ///
+    /// #[derive(Debug, clap::Subcommand)]
+    /// enum Command {
+    ///     Build,
+    /// }
+    ///
+    /// let cmd = Verbosity::<Accumulate>::augment_subcommands(Cli::command());
+    /// let cli = Cli::from_arg_matches(&cmd.get_matches()).unwrap_or_else(|e| e.exit());
+    /// ```
+    pub fn augment_subcommands(mut cmd: clap::Command) -> clap::Command {
+        for sub in cmd.get_subcommands_mut() {
+            let augmented = <Self as clap::Args>::augment_args(std::mem::take(sub));
+            *sub = Self::augment_subcommands(augmented);
+        }
+        cmd
+    }
+
+    /// Whether any verbosity flags are present on the command line.
+    ///
+    /// This counts any `--verbose` or `--quiet` (also when set from [`LogLevel::verbose_env`] or
+    /// [`LogLevel::quiet_env`]), the [`LogLevel::silent_long`] flag and a `--log-profile`.  A
+    /// `--no-verbose` or `--no-quiet` that resets the count to zero doesn't count.
+    ///
+    /// The `env_logger` and `env-filter` helpers use this to decide whether a level from `RUST_LOG`
+    /// may replace the flags' level.
+    pub fn is_present(&self) -> bool {
+        self.verbose != 0 || self.quiet != 0 || self.silent || self.profile.is_some()
+    }
+
+    /// If the user requested complete silence (i.e. not just no-logging).
+    pub fn is_silent(&self) -> bool {
+        self.filter() == VerbosityFilter::Off
+    }
+
+    /// Whether the resulting level is the default one, even if flags were passed
+    ///
+    /// Unlike [`Verbosity::is_present`], `-v -q` still counts as the default.  Use this to decide
+    /// whether to defer to another source, like `RUST_LOG`.  The default is
+    /// [`LogLevel::default_filter`], unless replaced with [`Verbosity::set_default`].
+    pub fn is_default(&self) -> bool {
+        let default = self.default.unwrap_or_else(L::default_filter);
+        self.filter() == clamp::<L>(default)
+    }
+
+    /// Whether warnings would be emitted
+    pub fn is_warn_enabled(&self) -> bool {
+        self.filter().is_enabled(VerbosityFilter::Warn)
+    }
+
+    /// Whether info messages would be emitted
+    pub fn is_info_enabled(&self) -> bool {
+        self.filter().is_enabled(VerbosityFilter::Info)
+    }
+
+    /// Whether debug messages would be emitted
+    ///
+    /// Use this to skip expensive diagnostics without setting up a logger:
+    /// ```rust
+    /// # use clap_verbosity_flag::Verbosity;
+    /// let verbosity = <Verbosity>::new(3, 0);
+    /// if verbosity.is_debug_enabled() {
+    ///     // dump internal state
+    /// }
+    /// # assert!(verbosity.is_debug_enabled());
+    /// ```
+    pub fn is_debug_enabled(&self) -> bool {
+        self.filter().is_enabled(VerbosityFilter::Debug)
+    }
+
+    /// Whether trace messages would be emitted
+    pub fn is_trace_enabled(&self) -> bool {
+        self.filter().is_enabled(VerbosityFilter::Trace)
+    }
+
+    /// The [`LogLevel::profiles`] entry chosen with `--log-profile`
+    pub fn profile(&self) -> Option<&'static str> {
+        self.profile
+    }
+
+    /// [`Verbosity::filter`] if any flags were passed, otherwise `fallback`
+    ///
+    /// Use this to layer the command line over a level from a config file:
+    /// ```rust
+    /// # use clap_verbosity_flag::{Verbosity, VerbosityFilter};
+    /// let config = VerbosityFilter::Info;
+    /// assert_eq!(<Verbosity>::new(0, 0).or(config), VerbosityFilter::Info);
+    /// assert_eq!(<Verbosity>::new(0, 1).or(config), VerbosityFilter::Off);
+    /// ```
+    ///
+    /// Counts from [`LogLevel::verbose_env`] and [`LogLevel::quiet_env`] take precedence over
+    /// `fallback` too.
+    pub fn or(&self, fallback: VerbosityFilter) -> VerbosityFilter {
+        if self.is_present() {
+            self.filter()
+        } else {
+            fallback
+        }
+    }
+
+    /// `self` if any flags were passed, otherwise `other`
+    ///
+    /// See [`Verbosity::or`].
+    pub fn merge(self, other: Self) -> Self {
+        if self.is_present() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// The level as env-filter directives, followed by a directive for each of `overrides`
+    ///
+    /// Feed this to `EnvFilter::new`, write it to a config file, or pass it to another process in
+    /// `RUST_LOG`:
+    /// ```rust
+    /// # use clap_verbosity_flag::{InfoLevel, Verbosity, VerbosityFilter};
+    /// let verbosity = Verbosity::<InfoLevel>::new(0, 0);
+    /// let directives = verbosity.as_env_filter_string(&[("hyper", VerbosityFilter::Warn)]);
+    /// assert_eq!(directives, "info,hyper=warn");
+    /// ```
+    pub fn as_env_filter_string(&self, overrides: &[(&str, VerbosityFilter)]) -> String {
+        let targets = overrides
+            .iter()
+            .map(|(target, filter)| format!("{target}={filter}"));
+        std::iter::once(self.filter().to_string())
+            .chain(targets)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// The `RUST_BACKTRACE` value for the level: `1` at debug, `full` at trace
+    ///
+    /// `None` below debug, so backtraces stay as configured by the environment.
+    /// ```rust
+    /// # use clap_verbosity_flag::{InfoLevel, Verbosity};
+    /// assert_eq!(Verbosity::<InfoLevel>::new(0, 0).backtrace_env(), None);
+    /// assert_eq!(Verbosity::<InfoLevel>::new(1, 0).backtrace_env(), Some("1"));
+    /// assert_eq!(Verbosity::<InfoLevel>::new(2, 0).backtrace_env(), Some("full"));
+    /// ```
+    pub fn backtrace_env(&self) -> Option<&'static str> {
+        match self.filter() {
+            VerbosityFilter::Debug => Some("1"),
+            VerbosityFilter::Trace => Some("full"),
+            _ => None,
+        }
+    }
+
+    /// Capture backtraces on panics and errors according to [`Verbosity::backtrace_env`]
+    ///
+    /// Sets `RUST_BACKTRACE` and `RUST_LIB_BACKTRACE`, which `std::backtrace::Backtrace::capture`
+    /// and the default panic hook read, so asking for debug output is enough to get a backtrace
+    /// from a crash.
+    /// Below debug, the environment is left untouched.  Call this at the start of `main`, before
+    /// any other thread is spawned.
+    pub fn configure_backtraces(&self) {
+        if let Some(value) = self.backtrace_env() {
+            std::env::set_var("RUST_BACKTRACE", value);
+            std::env::set_var("RUST_LIB_BACKTRACE", value);
+        }
+    }
+
+    /// Gets the filter that should be applied to the logger.
+    pub fn filter(&self) -> VerbosityFilter {
+        self.filter_with_verbose(self.verbose)
+    }
+
+    /// [`Verbosity::filter`] as if `--verbose` had been passed `verbose` times
+    fn filter_with_verbose(&self, verbose: u8) -> VerbosityFilter {
+        if self.silent {
+            return VerbosityFilter::Off;
+        }
+        let filter = match self.base() {
+            Some(base) => base.with_offset(i16::from(verbose) - i16::from(self.quiet)),
+            None => L::filter_for(verbose, self.quiet),
+        };
+        clamp::<L>(filter)
+    }
+
+    /// The `--log-profile` level, or else the [`Verbosity::set_default`] one, if any
+    fn base(&self) -> Option<VerbosityFilter> {
+        L::profiles()
+            .iter()
+            .find(|(name, _)| Some(*name) == self.profile)
+            .map(|(_, base)| *base)
+            .or(self.default)
+    }
+
+    /// The level without any `-v` or `-q`
+    ///
+    /// This is the `--log-profile` level, the [`Verbosity::set_default`] one or
+    /// [`LogLevel::default_filter`], limited by [`LogLevel::min_filter`] and
+    /// [`LogLevel::max_filter`].
+    #[cfg(feature = "tracing-subscriber")]
+    pub(crate) fn baseline(&self) -> VerbosityFilter {
+        clamp::<L>(self.base().unwrap_or_else(L::default_filter))
+    }
+
+    /// The level outside of a [`ScopedVerbosity`][crate::tracing_subscriber::ScopedVerbosity]'s
+    /// scopes
+    ///
+    /// Flags only raise the level within the scopes, while `-q` and `--silent` apply everywhere.
+    #[cfg(feature = "tracing-subscriber")]
+    pub(crate) fn outside_scopes(&self) -> VerbosityFilter {
+        self.filter().min(self.baseline())
+    }
+}
+
+#[cfg(feature = "log")]
+impl<L: LogLevel> Verbosity<L> {
+    /// Get the log level.
+    ///
+    /// `None` means all output is disabled.
+    pub fn log_level(&self) -> Option<log::Level> {
+        self.filter().into()
+    }
+
+    /// Get the log level filter.
+    pub fn log_level_filter(&self) -> log::LevelFilter {
+        self.filter().into()
+    }
+
+    /// Set the global [`log::max_level`][::log::max_level] from the flags
+    ///
+    /// Use this with your own [`log::Log`][::log::Log] implementation, which then only sees enabled
+    /// records:
+    /// ```rust
+    /// # use clap_verbosity_flag::Verbosity;
+    /// # use clap_verbosity_flag::log::LevelFilter;
+    /// <Verbosity>::new(2, 0).apply();
+    /// assert_eq!(log::max_level(), LevelFilter::Info);
+    /// ```
+    pub fn apply(&self) {
+        ::log::set_max_level(self.log_level_filter());
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<L: LogLevel> Verbosity<L> {
+    /// Get the tracing level.
+    ///
+    /// `None` means all output is disabled.
+    pub fn tracing_level(&self) -> Option<tracing_core::Level> {
+        self.filter().into()
+    }
+
+    /// Get the tracing level filter.
+    pub fn tracing_level_filter(&self) -> tracing_core::LevelFilter {
+        self.filter().into()
+    }
+}
+
+impl<L: LogLevel> clap::FromArgMatches for Verbosity<L> {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut verbosity = Self::new(0, 0);
+        verbosity.update_from_arg_matches(matches)?;
+        Ok(verbosity)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        self.update_from_arg_matches_with_env(matches, |name| std::env::var_os(name));
+        Ok(())
+    }
+}
+
+impl<L: LogLevel> Verbosity<L> {
+    /// [`clap::FromArgMatches::update_from_arg_matches`], reading cargo's variables from `env`
+    fn update_from_arg_matches_with_env(
+        &mut self,
+        matches: &clap::ArgMatches,
+        env: impl Fn(&str) -> Option<OsString>,
+    ) {
+        if let Some((verbose, source)) = get_count::<L>(matches, &prefixed::<L>(VERBOSE_ID)) {
+            self.verbose = verbose;
+            self.verbose_source = source;
+            if L::cargo_term_env() && cargo_term_flag(env("CARGO_TERM_VERBOSE")) {
+                self.verbose = self.verbose.saturating_add(1);
+                self.verbose_source = self.verbose_source.max(Some(ValueSource::EnvVariable));
+            }
+        }
+        if let Some((quiet, source)) = get_count::<L>(matches, &prefixed::<L>(QUIET_ID)) {
+            self.quiet = quiet;
+            self.quiet_source = source;
+            if L::cargo_term_env() && cargo_term_flag(env("CARGO_TERM_QUIET")) {
+                self.quiet = self.quiet.saturating_add(1);
+                self.quiet_source = self.quiet_source.max(Some(ValueSource::EnvVariable));
+            }
+        }
+        if get_flag::<L>(matches, &prefixed::<L>(NO_VERBOSE_ID)) {
+            self.verbose = 0;
+            self.verbose_source = Some(ValueSource::CommandLine);
+        }
+        if get_flag::<L>(matches, &prefixed::<L>(NO_QUIET_ID)) {
+            self.quiet = 0;
+            self.quiet_source = Some(ValueSource::CommandLine);
+        }
+        self.silent = get_flag::<L>(matches, &prefixed::<L>(SILENT_ID));
+        if let Ok(Some(name)) = matches.try_get_one::<String>(&prefixed::<L>(PROFILE_ID)) {
+            self.profile = L::profiles()
+                .iter()
+                .map(|(profile, _)| *profile)
+                .find(|profile| profile == name);
+        }
+    }
+}
+
+/// Limit `filter` to [`LogLevel::min_filter`] through [`LogLevel::max_filter`]
+fn clamp<L: LogLevel>(filter: VerbosityFilter) -> VerbosityFilter {
+    let (min, max) = (L::min_filter(), L::max_filter());
+    if max < filter {
+        max
+    } else if filter < min {
+        min
+    } else {
+        filter
+    }
+}
+
+fn occurrences<L: LogLevel>(matches: &clap::ArgMatches, id: &str) -> u8 {
+    if L::numeric_values() {
+        matches
+            .get_many::<u8>(id)
+            .into_iter()
+            .flatten()
+            .fold(0, |count, n| count.saturating_add(*n))
+    } else {
+        matches.get_count(id)
+    }
+}
+
+/// Whether cargo set a `term` config value to `true`
+fn cargo_term_flag(value: Option<OsString>) -> bool {
+    value
+        .map(|value| value.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
+/// Read a count flag, summing it across subcommands for [`LogLevel::accumulate_subcommands`]
+fn get_count<L: LogLevel>(
+    matches: &clap::ArgMatches,
+    id: &str,
+) -> Option<(u8, Option<ValueSource>)> {
+    if !matches.contains_id(id) {
+        return None;
+    }
+
+    let mut count = occurrences::<L>(matches, id);
+    let mut source = matches.value_source(id);
+    if L::accumulate_subcommands() {
+        let mut current = matches;
+        while let Some((_, sub)) = current.subcommand() {
+            if sub.contains_id(id) {
+                count = count.saturating_add(occurrences::<L>(sub, id));
+                source = source.max(sub.value_source(id));
+            }
+            current = sub;
+        }
+    }
+    Some((count, source))
+}
+
+/// Read an optional flag, checking each subcommand for [`LogLevel::accumulate_subcommands`]
+fn get_flag<L: LogLevel>(matches: &clap::ArgMatches, id: &str) -> bool {
+    let is_set = |matches: &clap::ArgMatches| matches!(matches.try_get_one(id), Ok(Some(true)));
+    let mut set = is_set(matches);
+    if L::accumulate_subcommands() {
+        let mut current = matches;
+        while let Some((_, sub)) = current.subcommand() {
+            set |= is_set(sub);
+            current = sub;
+        }
+    }
+    set
+}
+
+impl<L: LogLevel> clap::Args for Verbosity<L> {
+    fn group_id() -> Option<clap::Id> {
+        Some(clap::Id::from(prefixed::<L>(Self::GROUP_ID)))
+    }
+
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        let mut group = flag_group::<L>(Self::GROUP_ID, &[VERBOSE_ID, QUIET_ID]);
+        let mut quiet = quiet_arg::<L>();
+        if !L::allow_verbose_and_quiet() {
+            quiet = quiet.conflicts_with(prefixed::<L>(VERBOSE_ID));
+        }
+        let mut cmd = cmd.arg(verbose_arg::<L>()).arg(quiet);
+        if let Some(silent) = silent_arg::<L>() {
+            group = group.arg(prefixed::<L>(SILENT_ID));
+            cmd = cmd.arg(silent);
+        }
+        if !L::profiles().is_empty() {
+            group = group.arg(prefixed::<L>(PROFILE_ID));
+            cmd = cmd.arg(profile_arg::<L>());
+        }
+        if L::negations() {
+            group = group.args([prefixed::<L>(NO_VERBOSE_ID), prefixed::<L>(NO_QUIET_ID)]);
+            cmd = cmd
+                .arg(negation_arg::<L>(
+                    NO_VERBOSE_ID,
+                    VERBOSE_ID,
+                    L::verbose_long(),
+                ))
+                .arg(negation_arg::<L>(NO_QUIET_ID, QUIET_ID, L::quiet_long()));
+        }
+        cmd.group(group)
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+const VERBOSE_ID: &str = "verbose";
+const QUIET_ID: &str = "quiet";
+const SILENT_ID: &str = "silent";
+const PROFILE_ID: &str = "log-profile";
+const NO_VERBOSE_ID: &str = "no-verbose";
+const NO_QUIET_ID: &str = "no-quiet";
+
+fn verbose_arg<L: LogLevel>() -> clap::Arg {
+    let arg = count_arg::<L>(VERBOSE_ID)
+        .long(prefixed::<L>(L::verbose_long()))
+        .aliases(L::verbose_aliases().iter().copied())
+        .visible_aliases(L::verbose_visible_aliases().iter().copied())
+        .short(L::verbose_short())
+        .env(L::verbose_env());
+    let table = level_table::<L>(L::verbose_short(), L::verbose_long(), |n| {
+        L::filter_for(n, 0)
+    });
+    let max = L::max_filter();
+    let limit = (max != VerbosityFilter::Trace).then(|| format!("up to {}", L::filter_name(max)));
+    count_help(
+        arg,
+        L::verbose_help_text(),
+        L::verbose_long_help_text(),
+        table,
+        limit,
+    )
+}
+
+fn quiet_arg<L: LogLevel>() -> clap::Arg {
+    let arg = count_arg::<L>(QUIET_ID)
+        .long(prefixed::<L>(L::quiet_long()))
+        .aliases(L::quiet_aliases().iter().copied())
+        .visible_aliases(L::quiet_visible_aliases().iter().copied())
+        .short(L::quiet_short())
+        .env(L::quiet_env());
+    let table = level_table::<L>(L::quiet_short(), L::quiet_long(), |n| L::filter_for(0, n));
+    let min = L::min_filter();
+    let limit = (min != VerbosityFilter::Off).then(|| format!("down to {}", L::filter_name(min)));
+    count_help(
+        arg,
+        L::quiet_help_text(),
+        L::quiet_long_help_text(),
+        table,
+        limit,
+    )
+}
+
+/// Set the help for a count flag
+///
+/// Without custom long help, `help` is followed by the [`level_table`].  A `limit` from
+/// [`LogLevel::min_filter`] or [`LogLevel::max_filter`] is mentioned in the short help.
+fn count_help(
+    mut arg: clap::Arg,
+    help: Option<StyledStr>,
+    long_help: Option<StyledStr>,
+    table: Option<String>,
+    limit: Option<String>,
+) -> clap::Arg {
+    let long_help = long_help.or_else(|| match (&help, table?) {
+        (Some(help), table) => Some(format!("{help}\n\n{table}").into()),
+        (None, table) => Some(table.into()),
+    });
+    let help = match (help, limit) {
+        (Some(help), Some(limit)) => Some(format!("{help} ({limit})").into()),
+        (help, _) => help,
+    };
+    if let Some(help) = help {
+        arg = arg.help(help);
+    }
+    if let Some(long_help) = long_help {
+        arg = arg.long_help(long_help);
+    }
+    arg
+}
+
+/// Describe the level reached by each number of occurrences of a flag, e.g. `-v = warn, -vv = info`
+///
+/// `None` if the flag has no effect.
+fn level_table<L: LogLevel>(
+    short: Option<char>,
+    long: &'static str,
+    filter_for: impl Fn(u8) -> VerbosityFilter,
+) -> Option<String> {
+    let mut entries = Vec::new();
+    let mut previous = clamp::<L>(filter_for(0));
+    for count in 1..=u8::MAX {
+        let filter = clamp::<L>(filter_for(count));
+        if filter == previous {
+            break;
+        }
+        let flag = match short {
+            Some(short) => format!("-{}", short.to_string().repeat(count.into())),
+            None if count == 1 => format!("--{}", prefixed::<L>(long)),
+            None => format!("--{} x{count}", prefixed::<L>(long)),
+        };
+        entries.push(format!("{flag} = {}", L::filter_name(filter)));
+        previous = filter;
+    }
+    (!entries.is_empty()).then(|| entries.join(", "))
+}
+
+fn silent_arg<L: LogLevel>() -> Option<clap::Arg> {
+    let mut arg = flag_arg::<L>(SILENT_ID)
+        .long(prefixed::<L>(L::silent_long()?))
+        .action(clap::ArgAction::SetTrue);
+    if let Some(help) = L::silent_help_text() {
+        arg = arg.help(help);
+    }
+    if L::allow_verbose_and_quiet() {
+        Some(arg)
+    } else {
+        Some(arg.conflicts_with(prefixed::<L>(VERBOSE_ID)))
+    }
+}
+
+fn profile_arg<L: LogLevel>() -> clap::Arg {
+    let names = L::profiles().iter().map(|(name, _)| *name);
+    flag_arg::<L>(PROFILE_ID)
+        .long(prefixed::<L>(PROFILE_ID))
+        .value_name("PROFILE")
+        .value_parser(clap::builder::PossibleValuesParser::new(names))
+        .help("Preset logging level, adjusted by any -v or -q")
+}
+
+/// `--no-<long>`, resetting the `negated` count when it comes later on the command line
+fn negation_arg<L: LogLevel>(
+    id: &'static str,
+    negated: &'static str,
+    long: &'static str,
+) -> clap::Arg {
+    flag_arg::<L>(id)
+        .long(prefixed::<L>(&format!("no-{long}")))
+        .action(clap::ArgAction::SetTrue)
+        .overrides_with(prefixed::<L>(negated))
+        .help(format!("Cancel any earlier --{}", prefixed::<L>(long)))
+}
+
+fn count_arg<L: LogLevel>(id: &'static str) -> clap::Arg {
+    let arg = flag_arg::<L>(id);
+    if L::numeric_values() {
+        arg.action(clap::ArgAction::Append)
+            .value_parser(clap::value_parser!(u8))
+            .value_name("N")
+            .num_args(0..=1)
+            .require_equals(true)
+            .default_missing_value("1")
+            .default_value("0")
+            .hide_default_value(true)
+    } else {
+        arg.action(clap::ArgAction::Count)
+    }
+}
+
+fn flag_arg<L: LogLevel>(id: &'static str) -> clap::Arg {
+    let arg = clap::Arg::new(prefixed::<L>(id)).global(L::global() && !L::accumulate_subcommands());
+    help_placement::<L>(arg)
+}
+
+/// Apply the [`LogLevel`] hooks for where, and whether, `arg` shows up in help
+fn help_placement<L: LogLevel>(mut arg: clap::Arg) -> clap::Arg {
+    arg = arg.hide(L::hide()).hide_short_help(L::hide_short_help());
+    if let Some(heading) = L::help_heading() {
+        arg = arg.help_heading(heading);
+    }
+    if let Some(order) = L::display_order() {
+        arg = arg.display_order(order);
+    }
+    arg
+}
+
+/// A [`clap::ArgGroup`] over `args`, all with [`LogLevel::prefix`] applied
+fn flag_group<L: LogLevel>(id: &str, args: &[&str]) -> clap::ArgGroup {
+    clap::ArgGroup::new(prefixed::<L>(id))
+        .multiple(true)
+        .args(args.iter().map(|arg| prefixed::<L>(arg)))
+}
+
+/// `name` with [`LogLevel::prefix`] applied
+fn prefixed<L: LogLevel>(name: &str) -> String {
+    match L::prefix() {
+        Some(prefix) => format!("{prefix}-{name}"),
+        None => name.to_owned(),
+    }
+}
+
+impl<L: LogLevel> fmt::Display for Verbosity<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(L::filter_name(self.filter()))
+    }
+}
+
+impl<L: LogLevel> Verbosity<L> {
+    fn add_occurrences(&mut self, offset: i16) {
+        let count = u8::try_from(offset.unsigned_abs()).unwrap_or(u8::MAX);
+        if offset < 0 {
+            self.quiet = self.quiet.saturating_add(count);
+        } else {
+            self.verbose = self.verbose.saturating_add(count);
+        }
+    }
+}
+
+/// Compares the resulting [`Verbosity::filter`], not the counts
+impl<L: LogLevel> PartialEq for Verbosity<L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.filter() == other.filter()
+    }
+}
+
+impl<L: LogLevel> Eq for Verbosity<L> {}
+
+impl<L: LogLevel> PartialOrd for Verbosity<L> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders by the resulting [`Verbosity::filter`], see [`VerbosityFilter`]
+impl<L: LogLevel> Ord for Verbosity<L> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.filter().cmp(&other.filter())
+    }
+}
+
+impl<L: LogLevel> PartialEq<VerbosityFilter> for Verbosity<L> {
+    fn eq(&self, other: &VerbosityFilter) -> bool {
+        self.filter() == *other
+    }
+}
+
+impl<L: LogLevel> PartialOrd<VerbosityFilter> for Verbosity<L> {
+    fn partial_cmp(&self, other: &VerbosityFilter) -> Option<std::cmp::Ordering> {
+        Some(self.filter().cmp(other))
+    }
+}
+
+/// Adds `offset` occurrences of `--verbose`, or of `--quiet` when negative, saturating
+impl<L: LogLevel> ops::Add<i16> for Verbosity<L> {
+    type Output = Self;
+
+    fn add(mut self, offset: i16) -> Self {
+        self += offset;
+        self
+    }
+}
+
+impl<L: LogLevel> ops::AddAssign<i16> for Verbosity<L> {
+    fn add_assign(&mut self, offset: i16) {
+        self.add_occurrences(offset);
+    }
+}
+
+/// Adds `offset` occurrences of `--quiet`, or of `--verbose` when negative, saturating
+impl<L: LogLevel> ops::Sub<i16> for Verbosity<L> {
+    type Output = Self;
+
+    fn sub(mut self, offset: i16) -> Self {
+        self -= offset;
+        self
+    }
+}
+
+impl<L: LogLevel> ops::SubAssign<i16> for Verbosity<L> {
+    fn sub_assign(&mut self, offset: i16) {
+        self.add_occurrences(offset.saturating_neg());
+    }
+}
+
+/// The fewest `--verbose` or `--quiet` occurrences that lead to `filter`
+///
+/// When the flags can't reach `filter`, e.g. past [`LogLevel::max_filter`], it becomes the
+/// [`Verbosity::set_default`] instead, still limited by [`LogLevel::min_filter`] and
+/// [`LogLevel::max_filter`].
+/// ```rust
+/// # use clap_verbosity_flag::{Verbosity, VerbosityFilter};
+/// let verbosity = <Verbosity>::from(VerbosityFilter::Info);
+/// assert_eq!((verbosity.verbose(), verbosity.quiet()), (2, 0));
+/// ```
+impl<L: LogLevel> From<VerbosityFilter> for Verbosity<L> {
+    fn from(filter: VerbosityFilter) -> Self {
+        for count in 0..=u8::MAX {
+            if clamp::<L>(L::filter_for(count, 0)) == filter {
+                return Self::new(count, 0);
+            }
+            if clamp::<L>(L::filter_for(0, count)) == filter {
+                return Self::new(0, count);
+            }
+        }
+        Self::new(0, 0).with_default(filter)
+    }
+}
+
+/// Parse the same vocabulary as the command line
+///
+/// This accepts a level name or number like [`VerbosityFilterParser`] does, or repeated short
+/// flags:
+/// ```rust
+/// # use clap_verbosity_flag::{Verbosity, VerbosityFilter};
+/// let verbosity: Verbosity = "debug".parse().unwrap();
+/// assert_eq!(verbosity.filter(), VerbosityFilter::Debug);
+/// let verbosity: Verbosity = "2".parse().unwrap();
+/// assert_eq!(verbosity.filter(), VerbosityFilter::Warn);
+/// let verbosity: Verbosity = "-vv".parse().unwrap();
+/// assert_eq!(verbosity.verbose(), 2);
+/// ```
+impl<L: LogLevel> FromStr for Verbosity<L> {
+    type Err = ParseVerbosityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(flags) = s.strip_prefix('-') {
+            let count = |short: Option<char>| {
+                let short = short?;
+                let all_short = !flags.is_empty() && flags.chars().all(|c| c == short);
+                all_short.then(|| u8::try_from(flags.chars().count()).unwrap_or(u8::MAX))
+            };
+            if let Some(verbose) = count(L::verbose_short()) {
+                return Ok(Self::new(verbose, 0));
+            }
+            if let Some(quiet) = count(L::quiet_short()) {
+                return Ok(Self::new(0, quiet));
+            }
+        } else if let Some(filter) = parser::parse_filter(s) {
+            return Ok(filter.into());
+        }
+        Err(ParseVerbosityError(s.to_owned()))
+    }
+}
+
+impl<L: LogLevel> TryFrom<&str> for Verbosity<L> {
+    type Error = ParseVerbosityError;
+
+    fn try_from(s: &str) -> Result<Self, ParseVerbosityError> {
+        s.parse()
+    }
+}
+
+/// A string that is neither a level nor short flags, see `FromStr for Verbosity`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseVerbosityError(String);
+
+impl fmt::Display for ParseVerbosityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid verbosity {:?}, expected a level like `debug`, `0` to `5`, or flags like `-vv`",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseVerbosityError {}
+
+/// Customize the default log-level and associated help
+pub trait LogLevel {
+    /// Baseline level before applying `--verbose` and `--quiet`
+    fn default_filter() -> VerbosityFilter;
+
+    /// Level for `verbose` occurrences of `-v` and `quiet` occurrences of `-q`
+    ///
+    /// By default, each flag moves one level away from [`LogLevel::default_filter`].  Override
+    /// this for a non-linear scale, e.g. where the first `-v` jumps straight to debug.  The result
+    /// is still limited by [`LogLevel::min_filter`] and [`LogLevel::max_filter`].
+    fn filter_for(verbose: u8, quiet: u8) -> VerbosityFilter {
+        Self::default_filter().with_offset(verbose as i16 - quiet as i16)
+    }
+
+    /// Most verbose level that the flags can reach
+    ///
+    /// e.g. return [`VerbosityFilter::Debug`] so extra `-v`s never unlock traces in release
+    /// builds.  The short help for `--verbose` mentions the cap.
+    fn max_filter() -> VerbosityFilter {
+        VerbosityFilter::Trace
+    }
+
+    /// Least verbose level that the flags can reach
+    ///
+    /// e.g. return [`VerbosityFilter::Error`] so stacking `-qqq` never hides errors.  The short
+    /// help for `--quiet` mentions the floor.  An explicit [`LogLevel::silent_long`] flag still
+    /// turns logging off.
+    fn min_filter() -> VerbosityFilter {
+        VerbosityFilter::Off
+    }
+
+    /// Long flag for increasing verbosity
+    fn verbose_long() -> &'static str {
+        "verbose"
+    }
+
+    /// Short flag for increasing verbosity
+    ///
+    /// Pick other letters when `-v` or `-q` are already taken, e.g. by a git-style `-v` for
+    /// `--version`:
+    /// ```rust
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::{LogLevel, Verbosity, VerbosityFilter};
+    /// #[derive(Debug)]
+    /// struct Level;
+    ///
+    /// impl LogLevel for Level {
+    ///     fn default_filter() -> VerbosityFilter {
+    ///         VerbosityFilter::Error
+    ///     }
+    ///
+    ///     fn verbose_short() -> Option<char> {
+    ///         Some('d')
+    ///     }
+    ///
+    ///     fn quiet_short() -> Option<char> {
+    ///         Some('s')
+    ///     }
+    /// }
+    ///
+    /// #[derive(Debug, Parser)]
+    /// #[command(version, disable_version_flag = true)]
+    /// struct Cli {
+    ///     #[command(flatten)]
+    ///     verbose: Verbosity<Level>,
+    ///
+    ///     /// Print version
+    ///     #[arg(short = 'v', long, action = clap::ArgAction::Version)]
+    ///     version: (),
+    /// }
+    ///
+    /// let cli = Cli::parse_from(["cmd", "-dd"]);
+    /// assert_eq!(cli.verbose.filter(), VerbosityFilter::Info);
+    /// ```
+    ///
+    /// Return `None` to only generate the long flag.
+    fn verbose_short() -> Option<char> {
+        Some('v')
+    }
+
+    /// Hidden alternatives to [`LogLevel::verbose_long`], e.g. legacy names
+    fn verbose_aliases() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Alternatives to [`LogLevel::verbose_long`] that are listed in help
+    fn verbose_visible_aliases() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Long flag for decreasing verbosity
+    fn quiet_long() -> &'static str {
+        "quiet"
+    }
+
+    /// Hidden alternatives to [`LogLevel::quiet_long`], e.g. legacy names
+    fn quiet_aliases() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Alternatives to [`LogLevel::quiet_long`] that are listed in help
+    fn quiet_visible_aliases() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Short flag for decreasing verbosity
+    ///
+    /// Return `None` to only generate the long flag.
+    fn quiet_short() -> Option<char> {
+        Some('q')
+    }
+
+    /// Short-help message for `--verbose`
+    fn verbose_help() -> Option<&'static str> {
+        Some("Increase logging verbosity")
+    }
+
+    /// Long-help message for `--verbose`
+    ///
+    /// When `None`, the short help is followed by the level each count leads to, e.g.
+    /// `-v = warn, -vv = info`.
+    fn verbose_long_help() -> Option<&'static str> {
+        None
+    }
+
+    /// Short-help message for `--quiet`
+    fn quiet_help() -> Option<&'static str> {
+        Some("Decrease logging verbosity")
+    }
+
+    /// Short-help message for `--verbose`, built at runtime
+    ///
+    /// Override this instead of [`LogLevel::verbose_help`] for help that isn't `'static`, e.g.
+    /// when it is localized at startup.
+    fn verbose_help_text() -> Option<StyledStr> {
+        Self::verbose_help().map(StyledStr::from)
+    }
+
+    /// Long-help message for `--verbose`, built at runtime
+    ///
+    /// See [`LogLevel::verbose_help_text`].
+    fn verbose_long_help_text() -> Option<StyledStr> {
+        Self::verbose_long_help().map(StyledStr::from)
+    }
+
+    /// Long-help message for `--quiet`
+    ///
+    /// When `None`, the short help is followed by the level each count leads to, e.g.
+    /// `-q = off`.
+    fn quiet_long_help() -> Option<&'static str> {
+        None
+    }
+
+    /// Short-help message for `--quiet`, built at runtime
+    ///
+    /// See [`LogLevel::verbose_help_text`].
+    fn quiet_help_text() -> Option<StyledStr> {
+        Self::quiet_help().map(StyledStr::from)
+    }
+
+    /// Long-help message for `--quiet`, built at runtime
+    ///
+    /// See [`LogLevel::verbose_help_text`].
+    fn quiet_long_help_text() -> Option<StyledStr> {
+        Self::quiet_long_help().map(StyledStr::from)
+    }
+
+    /// Long flag that turns all logging off, regardless of the default level
+    ///
+    /// No flag is generated unless this returns a name, e.g. `Some("silent")`.  Unlike stacking
+    /// `-qqq`, this doesn't depend on how far the default is from [`VerbosityFilter::Off`].
+    fn silent_long() -> Option<&'static str> {
+        None
+    }
+
+    /// Help message for the [`LogLevel::silent_long`] flag
+    fn silent_help() -> Option<&'static str> {
+        Some("Disable all logging")
+    }
+
+    /// Help message for the [`LogLevel::silent_long`] flag, built at runtime
+    ///
+    /// See [`LogLevel::verbose_help_text`].
+    fn silent_help_text() -> Option<StyledStr> {
+        Self::silent_help().map(StyledStr::from)
+    }
+
+    /// User-facing name for `filter`, e.g. to localize it
+    ///
+    /// Used when displaying a [`Verbosity`].  The canonical, lowercase names used when displaying
+    /// a [`VerbosityFilter`] are unaffected.
+    fn filter_name(filter: VerbosityFilter) -> &'static str {
+        filter.as_str()
+    }
+
+    /// Styles for each level's label when a logger set up by this crate writes a record
+    fn level_styles() -> LevelStyles {
+        LevelStyles::styled()
+    }
+
+    /// Prefix for the flags' long names and ids, to flatten more than one set into a command
+    ///
+    /// e.g. `Some("client")` generates `--client-verbose` and `--client-quiet`, with ids like
+    /// `client-verbose`.  The [`Verbosity::GROUP_ID`] and other id constants get the same
+    /// prefix.  Short flags are unaffected, so give each set different ones or none at all.
+    fn prefix() -> Option<&'static str> {
+        None
+    }
+
+    /// Heading to group the flags under in help, instead of the command's current heading
+    fn help_heading() -> Option<&'static str> {
+        None
+    }
+
+    /// Position of the flags in help, see [`clap::Arg::display_order`]
+    ///
+    /// e.g. return `Some(usize::MAX)` to list them after your own options.
+    fn display_order() -> Option<usize> {
+        None
+    }
+
+    /// Leave the flags out of `--help` and `-h` entirely
+    fn hide() -> bool {
+        false
+    }
+
+    /// Leave the flags out of `-h`, only showing them in `--help`
+    fn hide_short_help() -> bool {
+        false
+    }
+
+    /// Environment variable holding a `--verbose` count, used when the flag is absent
+    ///
+    /// e.g. with `MYAPP_VERBOSE`, `MYAPP_VERBOSE=2 myapp` is the same as `myapp -vv`.
+    fn verbose_env() -> Option<&'static str> {
+        None
+    }
+
+    /// Environment variable holding a `--quiet` count, used when the flag is absent
+    ///
+    /// Values from the environment conflict with the opposite flag like ones passed on the
+    /// command line, unless [`LogLevel::allow_verbose_and_quiet`] is enabled.
+    fn quiet_env() -> Option<&'static str> {
+        None
+    }
+
+    /// Named presets for a `--log-profile <PROFILE>` option, e.g. `dev`, `prod` and `ci`
+    ///
+    /// No option is generated when this is empty.  `-v` and `-q` move one level at a time from
+    /// the chosen profile's level, instead of using [`LogLevel::filter_for`].
+    fn profiles() -> &'static [(&'static str, VerbosityFilter)] {
+        &[]
+    }
+
+    /// Also generate `--no-verbose` and `--no-quiet`, resetting the counts before them
+    ///
+    /// This lets users cancel flags injected by a shell alias, e.g. `alias tool='tool -v'` and
+    /// then `tool --no-verbose`.  Whichever of `-v` and `--no-verbose` comes last wins.
+    fn negations() -> bool {
+        false
+    }
+
+    /// Accept `--verbose` and `--quiet` together, using the difference between them
+    ///
+    /// This lets users override a `-q` injected by a shell alias or wrapper script with `-vv`.
+    fn allow_verbose_and_quiet() -> bool {
+        false
+    }
+
+    /// Also accept a count as a value, e.g. `--verbose=3` or `-q=2`
+    ///
+    /// Repeated flags and values are added together, so `-v -v=2` is the same as `-vvv`.
+    fn numeric_values() -> bool {
+        false
+    }
+
+    /// Whether the flags are accepted after subcommands, see [`clap::Arg::global`]
+    fn global() -> bool {
+        true
+    }
+
+    /// Treat cargo's `CARGO_TERM_VERBOSE` and `CARGO_TERM_QUIET` as an extra `-v` or `-q`
+    ///
+    /// For cargo subcommands, this makes `cargo -v mysubcommand` behave like
+    /// `cargo mysubcommand -v`.
+    fn cargo_term_env() -> bool {
+        false
+    }
+
+    /// Sum occurrences from every level of the subcommand tree
+    ///
+    /// By default, the flags are `global` and `mytool -v sub -v` counts as a single `-v`.  When
+    /// this returns `true`, the flags are registered on each subcommand individually by
+    /// [`Verbosity::augment_subcommands`] and all occurrences are added up.
+    fn accumulate_subcommands() -> bool {
+        false
+    }
+}
+
+/// A representation of the log level filter.
+///
+/// Used to calculate the log level and filter.  Filters are ordered by how much they let through,
+/// from [`VerbosityFilter::Off`] to [`VerbosityFilter::Trace`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VerbosityFilter {
+    Off = 0,
+    Error = 1,
+    Warn = 2,
+    Info = 3,
+    Debug = 4,
+    Trace = 5,
+}
+
+impl VerbosityFilter {
+    /// Every filter, from [`VerbosityFilter::Off`] to [`VerbosityFilter::Trace`]
+    pub const ALL: [VerbosityFilter; 6] = [
+        Self::Off,
+        Self::Error,
+        Self::Warn,
+        Self::Info,
+        Self::Debug,
+        Self::Trace,
+    ];
+
+    /// Iterate over [`VerbosityFilter::ALL`]
+    pub fn iter() -> impl DoubleEndedIterator<Item = VerbosityFilter> + ExactSizeIterator {
+        Self::ALL.into_iter()
+    }
+
+    /// Apply an offset to the filter level.
+    ///
+    /// Negative values will decrease the verbosity, while positive values will increase it.
+    /// The result saturates at [`VerbosityFilter::Off`] and [`VerbosityFilter::Trace`]:
+    /// ```rust
+    /// # use clap_verbosity_flag::VerbosityFilter;
+    /// assert_eq!(VerbosityFilter::Warn.with_offset(2), VerbosityFilter::Debug);
+    /// assert_eq!(VerbosityFilter::Warn.with_offset(-5), VerbosityFilter::Off);
+    /// assert_eq!(VerbosityFilter::Off.with_offset(i16::MAX), VerbosityFilter::Trace);
+    /// ```
+    ///
+    /// This is a `const fn`, so levels can be derived at compile time:
+    /// ```rust
+    /// # use clap_verbosity_flag::VerbosityFilter;
+    /// const VERBOSE: VerbosityFilter = VerbosityFilter::Error.with_offset(2);
+    /// # assert_eq!(VERBOSE, VerbosityFilter::Info);
+    /// ```
+    pub const fn with_offset(&self, offset: i16) -> VerbosityFilter {
+        match self.value().saturating_add(offset) {
+            i16::MIN..=0 => Self::Off,
+            1 => Self::Error,
+            2 => Self::Warn,
+            3 => Self::Info,
+            4 => Self::Debug,
+            5..=i16::MAX => Self::Trace,
+        }
+    }
+
+    /// Position on the scale from [`VerbosityFilter::Off`] to [`VerbosityFilter::Trace`]
+    const fn value(&self) -> i16 {
+        *self as i16
+    }
+
+    /// The canonical, lowercase name of the filter
+    ///
+    /// This is what [`VerbosityFilter`]'s `Display` writes, without allocating:
+    /// ```rust
+    /// # use clap_verbosity_flag::VerbosityFilter;
+    /// const LEVEL: &str = VerbosityFilter::Debug.as_str();
+    /// assert_eq!(LEVEL, "debug");
+    /// ```
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Error => "error",
+            Self::Warn => "warn",
+            Self::Info => "info",
+            Self::Debug => "debug",
+            Self::Trace => "trace",
+        }
+    }
+
+    /// Whether a message at `level` would be emitted under this filter
+    ///
+    /// Accepts a [`VerbosityFilter`] as well as `log::Level` and `tracing::Level` with the
+    /// matching features.  Nothing is emitted at [`VerbosityFilter::Off`].
+    /// ```rust
+    /// # use clap_verbosity_flag::VerbosityFilter;
+    /// assert!(VerbosityFilter::Info.is_enabled(VerbosityFilter::Warn));
+    /// assert!(!VerbosityFilter::Info.is_enabled(VerbosityFilter::Debug));
+    /// ```
+    pub fn is_enabled(&self, level: impl Into<VerbosityFilter>) -> bool {
+        let level = level.into();
+        level != Self::Off && level <= *self
+    }
+
+    /// The threshold for Python's `logging.Logger.setLevel`
+    ///
+    /// Python has no trace level, so [`VerbosityFilter::Trace`] is `5`, below `DEBUG`.
+    /// [`VerbosityFilter::Off`] is `60`, above `CRITICAL`, so nothing passes.
+    /// ```rust
+    /// # use clap_verbosity_flag::VerbosityFilter;
+    /// assert_eq!(VerbosityFilter::Warn.to_python_level(), 30);
+    /// ```
+    pub const fn to_python_level(&self) -> u8 {
+        match self {
+            Self::Off => 60,
+            Self::Error => 40,
+            Self::Warn => 30,
+            Self::Info => 20,
+            Self::Debug => 10,
+            Self::Trace => 5,
+        }
+    }
+
+    /// The filter letting through the same levels as a Python `logging` threshold
+    ///
+    /// A threshold between two levels rounds towards [`VerbosityFilter::Off`], like Python does,
+    /// except that `CRITICAL` is treated as [`VerbosityFilter::Error`].  `NOTSET` (`0`) lets
+    /// everything through.
+    /// ```rust
+    /// # use clap_verbosity_flag::VerbosityFilter;
+    /// assert_eq!(VerbosityFilter::from_python_level(20), VerbosityFilter::Info);
+    /// assert_eq!(VerbosityFilter::from_python_level(15), VerbosityFilter::Info);
+    /// assert_eq!(VerbosityFilter::from_python_level(50), VerbosityFilter::Error);
+    /// ```
+    pub const fn from_python_level(level: u8) -> VerbosityFilter {
+        match level {
+            0..=5 => Self::Trace,
+            6..=10 => Self::Debug,
+            11..=20 => Self::Info,
+            21..=30 => Self::Warn,
+            31..=50 => Self::Error,
+            51..=u8::MAX => Self::Off,
+        }
+    }
+}
+
+/// Moves `offset` levels towards [`VerbosityFilter::Trace`], saturating like `-v`
+impl ops::Add<i16> for VerbosityFilter {
+    type Output = Self;
+
+    fn add(self, offset: i16) -> Self {
+        self.with_offset(offset)
+    }
+}
+
+impl ops::AddAssign<i16> for VerbosityFilter {
+    fn add_assign(&mut self, offset: i16) {
+        *self = *self + offset;
+    }
+}
+
+/// Moves `offset` levels towards [`VerbosityFilter::Off`], saturating like `-q`
+impl ops::Sub<i16> for VerbosityFilter {
+    type Output = Self;
+
+    fn sub(self, offset: i16) -> Self {
+        self.with_offset(offset.saturating_neg())
+    }
+}
+
+impl ops::SubAssign<i16> for VerbosityFilter {
+    fn sub_assign(&mut self, offset: i16) {
+        *self = *self - offset;
+    }
+}
+
+/// Position on the scale from `0` for [`VerbosityFilter::Off`] to `5` for
+/// [`VerbosityFilter::Trace`]
+///
+/// Higher numbers let more through, so they can be compared against numeric thresholds:
+/// ```rust
+/// # use clap_verbosity_flag::VerbosityFilter;
+/// assert_eq!(u8::from(VerbosityFilter::Warn), 2);
+/// assert_eq!(VerbosityFilter::try_from(4), Ok(VerbosityFilter::Debug));
+/// assert!(VerbosityFilter::try_from(6).is_err());
+/// ```
+impl From<VerbosityFilter> for u8 {
+    fn from(filter: VerbosityFilter) -> Self {
+        filter as u8
+    }
+}
+
+/// Inverse of `From<VerbosityFilter> for u8`, failing above `5`
+impl TryFrom<u8> for VerbosityFilter {
+    type Error = FilterOutOfRange;
+
+    fn try_from(value: u8) -> Result<Self, FilterOutOfRange> {
+        Self::ALL
+            .get(usize::from(value))
+            .copied()
+            .ok_or(FilterOutOfRange(value))
+    }
+}
+
+/// A number outside of the `0` to `5` scale of [`VerbosityFilter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FilterOutOfRange(u8);
+
+impl FilterOutOfRange {
+    /// The number that was out of range
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+impl fmt::Display for FilterOutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "verbosity {} is out of range 0..=5", self.0)
+    }
+}
+
+impl std::error::Error for FilterOutOfRange {}
+
+/// Possible values are the canonical, lowercase names, for your own level arguments:
+/// ```rust
+/// # use clap::Parser;
+/// # use clap_verbosity_flag::VerbosityFilter;
+/// #[derive(Debug, Parser)]
+/// struct Cli {
+///     /// Level for messages from dependencies
+///     #[arg(long, value_enum, default_value_t = VerbosityFilter::Warn)]
+///     deps_level: VerbosityFilter,
+/// }
+///
+/// let cli = Cli::parse_from(["cmd", "--deps-level", "debug"]);
+/// assert_eq!(cli.deps_level, VerbosityFilter::Debug);
+/// ```
+impl clap::ValueEnum for VerbosityFilter {
+    fn value_variants<'a>() -> &'a [Self] {
+        &Self::ALL
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        Some(clap::builder::PossibleValue::new(self.as_str()))
+    }
+}
+
+/// Writes the canonical, lowercase name.
+///
+/// See [`LogLevel::filter_name`] for user-facing names.
+impl fmt::Display for VerbosityFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Default to [`VerbosityFilter::Error`]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ErrorLevel;
+
+impl LogLevel for ErrorLevel {
+    fn default_filter() -> VerbosityFilter {
+        VerbosityFilter::Error
+    }
+}
+
+/// Default to [`VerbosityFilter::Warn`]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct WarnLevel;
+
+impl LogLevel for WarnLevel {
+    fn default_filter() -> VerbosityFilter {
+        VerbosityFilter::Warn
+    }
+}
+
+/// Default to [`VerbosityFilter::Info`]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct InfoLevel;
+
+impl LogLevel for InfoLevel {
+    fn default_filter() -> VerbosityFilter {
+        VerbosityFilter::Info
+    }
+}
+
+/// Default to [`VerbosityFilter::Debug`]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DebugLevel;
+
+impl LogLevel for DebugLevel {
+    fn default_filter() -> VerbosityFilter {
+        VerbosityFilter::Debug
+    }
+}
+
+/// Default to [`VerbosityFilter::Trace`]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct TraceLevel;
+
+impl LogLevel for TraceLevel {
+    fn default_filter() -> VerbosityFilter {
+        VerbosityFilter::Trace
+    }
+}
+
+/// Default to [`VerbosityFilter::Off`] (no logging)
+#[derive(Copy, Clone, Debug, Default)]
+pub struct OffLevel;
+
+impl LogLevel for OffLevel {
+    fn default_filter() -> VerbosityFilter {
+        VerbosityFilter::Off
+    }
+}
+
+/// Default to the `N`th filter from [`VerbosityFilter::Off`], without defining a [`LogLevel`]
+///
+/// `0` is [`VerbosityFilter::Off`] and `5` or more is [`VerbosityFilter::Trace`]:
+/// ```rust
+/// # use clap::Parser;
+/// # use clap_verbosity_flag::{Level, Verbosity, VerbosityFilter};
+/// #[derive(Debug, Parser)]
+/// struct Cli {
+///     #[command(flatten)]
+///     verbose: Verbosity<Level<3>>,
+/// }
+///
+/// let cli = Cli::parse_from(["cmd", "-v"]);
+/// assert_eq!(cli.verbose.filter(), VerbosityFilter::Debug);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Level<const N: u8>;
+
+impl<const N: u8> LogLevel for Level<N> {
+    fn default_filter() -> VerbosityFilter {
+        VerbosityFilter::Off.with_offset(N.into())
+    }
+}
+
+/// Default to [`VerbosityFilter::Error`], with the first `-v` jumping straight to
+/// [`VerbosityFilter::Debug`]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct JumpToDebugLevel;
+
+impl LogLevel for JumpToDebugLevel {
+    fn default_filter() -> VerbosityFilter {
+        VerbosityFilter::Error
+    }
+
+    fn filter_for(verbose: u8, quiet: u8) -> VerbosityFilter {
+        let offset = verbose as i16 - quiet as i16;
+        if 0 < offset {
+            VerbosityFilter::Debug.with_offset(offset - 1)
+        } else {
+            Self::default_filter().with_offset(offset)
+        }
+    }
+}
+
+/// Default to [`VerbosityFilter::Error`], with each `-v` or `-q` moving two levels
+#[derive(Copy, Clone, Debug, Default)]
+pub struct TwoStepLevel;
+
+impl LogLevel for TwoStepLevel {
+    fn default_filter() -> VerbosityFilter {
+        VerbosityFilter::Error
+    }
+
+    fn filter_for(verbose: u8, quiet: u8) -> VerbosityFilter {
+        Self::default_filter().with_offset(2 * (verbose as i16 - quiet as i16))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn verify_app() {
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity,
+        }
+
+        use clap::CommandFactory;
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn counts() {
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity,
+        }
+
+        use clap::Parser;
+        let cli = Cli::try_parse_from(["cmd", "-vvvvv"]).unwrap();
+        assert_eq!(cli.verbose.verbose(), 5);
+        assert_eq!(cli.verbose.quiet(), 0);
+        assert_eq!(cli.verbose.filter(), VerbosityFilter::Trace);
+
+        let verbosity = Verbosity::<ErrorLevel>::new(0, 2);
+        assert_eq!(verbosity.quiet(), 2);
+        assert_eq!(verbosity.offset(), -2);
+        assert_eq!(Verbosity::<ErrorLevel>::new(u8::MAX, 0).offset(), 255);
+    }
+
+    #[test]
+    fn from_filter() {
+        let counts = |v: Verbosity<ErrorLevel>| (v.verbose(), v.quiet());
+        assert_eq!(counts(VerbosityFilter::Error.into()), (0, 0));
+        assert_eq!(counts(VerbosityFilter::Off.into()), (0, 1));
+        assert_eq!(counts(VerbosityFilter::Trace.into()), (4, 0));
+
+        let v = Verbosity::<JumpToDebugLevel>::from(VerbosityFilter::Trace);
+        assert_eq!((v.verbose(), v.quiet()), (2, 0));
+        let v = Verbosity::<TwoStepLevel>::from(VerbosityFilter::Warn);
+        assert_eq!(v.filter(), VerbosityFilter::Warn);
+
+        for filter in VerbosityFilter::iter() {
+            assert_eq!(Verbosity::<InfoLevel>::from(filter).filter(), filter);
+        }
+    }
+
+    #[test]
+    fn from_str() {
+        let parse = |s: &str| {
+            s.parse::<Verbosity>()
+                .map(|v| (v.verbose(), v.quiet(), v.filter()))
+        };
+        assert_eq!(parse("Warning"), Ok((1, 0, VerbosityFilter::Warn)));
+        assert_eq!(parse("0"), Ok((0, 1, VerbosityFilter::Off)));
+        assert_eq!(parse("-vvv"), Ok((3, 0, VerbosityFilter::Debug)));
+        assert_eq!(parse("-q"), Ok((0, 1, VerbosityFilter::Off)));
+        assert!(parse("-").is_err());
+        assert!(parse("-vq").is_err());
+        assert!(parse("6").is_err());
+        let err = parse("loud").unwrap_err();
+        assert!(err.to_string().starts_with("invalid verbosity \"loud\""));
+
+        assert_eq!(
+            Verbosity::<InfoLevel>::try_from("trace").unwrap().verbose(),
+            2
+        );
+    }
+
+    #[test]
+    fn as_env_filter_string() {
+        let verbosity = Verbosity::<ErrorLevel>::new(0, 1);
+        assert_eq!(verbosity.as_env_filter_string(&[]), "off");
+        let overrides = [
+            ("hyper", VerbosityFilter::Warn),
+            ("app::db", VerbosityFilter::Trace),
+        ];
+        assert_eq!(
+            verbosity.as_env_filter_string(&overrides),
+            "off,hyper=warn,app::db=trace"
+        );
+    }
+
+    #[test]
+    fn const_fns() {
+        const VERBOSITY: Verbosity = Verbosity::new(2, 1);
+        const OFFSET: i16 = VERBOSITY.offset();
+        const FILTER: VerbosityFilter = VerbosityFilter::Error.with_offset(OFFSET);
+        assert_eq!(VERBOSITY.verbose(), 2);
+        assert_eq!(VERBOSITY.quiet(), 1);
+        assert_eq!(FILTER, VERBOSITY.filter());
+    }
+
+    #[test]
+    fn extra_verbosity() {
+        assert_eq!(Verbosity::<ErrorLevel>::new(3, 0).extra_verbosity(), 0);
+        assert_eq!(Verbosity::<ErrorLevel>::new(4, 0).extra_verbosity(), 0);
+        assert_eq!(Verbosity::<ErrorLevel>::new(6, 0).extra_verbosity(), 2);
+        assert_eq!(Verbosity::<ErrorLevel>::new(6, 1).extra_verbosity(), 1);
+        assert_eq!(Verbosity::<TraceLevel>::new(1, 0).extra_verbosity(), 1);
+        assert_eq!(
+            Verbosity::<JumpToDebugLevel>::new(3, 0).extra_verbosity(),
+            1
+        );
+
+        #[derive(Copy, Clone, Debug, Default)]
+        struct Capped;
+
+        impl LogLevel for Capped {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Error
+            }
+
+            fn max_filter() -> VerbosityFilter {
+                VerbosityFilter::Info
+            }
+        }
+
+        assert_eq!(Verbosity::<Capped>::new(4, 0).extra_verbosity(), 2);
+    }
+
+    #[test]
+    fn layering() {
+        let none = Verbosity::<InfoLevel>::new(0, 0);
+        let quiet = Verbosity::<InfoLevel>::new(0, 1);
+        let both = Verbosity::<InfoLevel>::new(1, 1);
+        assert_eq!(none.or(VerbosityFilter::Trace), VerbosityFilter::Trace);
+        assert_eq!(quiet.or(VerbosityFilter::Trace), VerbosityFilter::Warn);
+        assert_eq!(both.or(VerbosityFilter::Trace), VerbosityFilter::Info);
+
+        assert_eq!(none.merge(quiet).quiet(), 1);
+        assert_eq!(quiet.merge(none).quiet(), 1);
+        assert_eq!(both.merge(quiet).verbose(), 1);
+    }
+
+    #[test]
+    fn runtime_default() {
+        let mut verbosity = Verbosity::<ErrorLevel>::new(0, 0);
+        verbosity.set_default(VerbosityFilter::Info);
+        assert_eq!(verbosity.filter(), VerbosityFilter::Info);
+        assert!(!verbosity.is_present());
+        assert_eq!(verbosity.with_quiet(1).filter(), VerbosityFilter::Warn);
+        assert_eq!(
+            Verbosity::<JumpToDebugLevel>::new(1, 0)
+                .with_default(VerbosityFilter::Warn)
+                .filter(),
+            VerbosityFilter::Info
+        );
+    }
+
+    #[test]
+    fn is_default() {
+        assert!(Verbosity::<InfoLevel>::new(0, 0).is_default());
+        assert!(Verbosity::<InfoLevel>::new(1, 1).is_default());
+        assert!(!Verbosity::<InfoLevel>::new(1, 0).is_default());
+        assert!(!Verbosity::<InfoLevel>::new(0, 0)
+            .with_default(VerbosityFilter::Warn)
+            .with_verbose(1)
+            .is_default());
+        assert!(Verbosity::<InfoLevel>::new(0, 0)
+            .with_default(VerbosityFilter::Warn)
+            .is_default());
+    }
+
+    #[test]
+    fn is_level_enabled() {
+        let v = Verbosity::<ErrorLevel>::new(0, 0);
+        assert!(!v.is_warn_enabled());
+
+        let v = Verbosity::<ErrorLevel>::new(2, 0);
+        assert!(v.is_warn_enabled());
+        assert!(v.is_info_enabled());
+        assert!(!v.is_debug_enabled());
+        assert!(!v.is_trace_enabled());
+
+        let v = Verbosity::<TraceLevel>::new(0, 0);
+        assert!(v.is_trace_enabled());
+
+        let v = Verbosity::<TraceLevel>::new(0, 1);
+        assert!(v.is_debug_enabled());
+        assert!(!v.is_trace_enabled());
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(VerbosityFilter::Off < VerbosityFilter::Error);
+        assert!(VerbosityFilter::Error < VerbosityFilter::Warn);
+        assert!(VerbosityFilter::Warn < VerbosityFilter::Info);
+        assert!(VerbosityFilter::Info < VerbosityFilter::Debug);
+        assert!(VerbosityFilter::Debug < VerbosityFilter::Trace);
+
+        let verbosity = Verbosity::<ErrorLevel>::new(3, 0);
+        assert!(verbosity >= VerbosityFilter::Debug);
+        assert!(verbosity < VerbosityFilter::Trace);
+        assert_eq!(verbosity, VerbosityFilter::Debug);
+        assert_eq!(verbosity, Verbosity::new(4, 1));
+        assert!(Verbosity::<ErrorLevel>::new(0, 1) < verbosity);
+    }
+
+    #[test]
+    fn offset_ops() {
+        assert_eq!(VerbosityFilter::Warn + 1, VerbosityFilter::Info);
+        assert_eq!(VerbosityFilter::Warn + 10, VerbosityFilter::Trace);
+        assert_eq!(VerbosityFilter::Warn - 1, VerbosityFilter::Error);
+        assert_eq!(VerbosityFilter::Warn + i16::MIN, VerbosityFilter::Off);
+        assert_eq!(VerbosityFilter::Warn - i16::MIN, VerbosityFilter::Trace);
+        let mut filter = VerbosityFilter::Error;
+        filter += 2;
+        filter -= 1;
+        assert_eq!(filter, VerbosityFilter::Warn);
+
+        let verbosity = Verbosity::<ErrorLevel>::new(1, 0);
+        assert_eq!((verbosity + 1).filter(), VerbosityFilter::Info);
+        assert_eq!((verbosity - 2).filter(), VerbosityFilter::Off);
+        assert_eq!((verbosity + i16::MAX).verbose(), u8::MAX);
+        assert_eq!((verbosity - i16::MIN).verbose(), u8::MAX);
+        let mut verbosity = verbosity;
+        verbosity += -1;
+        verbosity -= -3;
+        assert_eq!((verbosity.verbose(), verbosity.quiet()), (4, 1));
+    }
+
+    #[test]
+    fn setters() {
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity,
+        }
+
+        use clap::Parser;
+        let mut verbosity = Cli::try_parse_from(["cmd", "-v"]).unwrap().verbose;
+        verbosity.set_verbose(3);
+        assert_eq!(verbosity.filter(), VerbosityFilter::Debug);
+        assert_eq!(verbosity.verbose_source(), None);
+        assert_eq!(verbosity.quiet_source(), Some(ValueSource::DefaultValue));
+        verbosity.set_quiet(1);
+        assert_eq!(verbosity.filter(), VerbosityFilter::Info);
+
+        let verbosity = Verbosity::<ErrorLevel>::default()
+            .with_verbose(1)
+            .with_quiet(2);
+        assert_eq!((verbosity.verbose(), verbosity.quiet()), (1, 2));
+        assert_eq!(verbosity.filter(), VerbosityFilter::Off);
+    }
+
+    #[test]
+    fn value_source() {
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity,
+        }
+
+        use clap::Parser;
+        let cli = Cli::try_parse_from(["cmd"]).unwrap();
+        assert_eq!(
+            cli.verbose.verbose_source(),
+            Some(ValueSource::DefaultValue)
+        );
+        assert_eq!(cli.verbose.quiet_source(), Some(ValueSource::DefaultValue));
+
+        let cli = Cli::try_parse_from(["cmd", "-q"]).unwrap();
+        assert_eq!(
+            cli.verbose.verbose_source(),
+            Some(ValueSource::DefaultValue)
+        );
+        assert_eq!(cli.verbose.quiet_source(), Some(ValueSource::CommandLine));
+
+        let verbose = Verbosity::<ErrorLevel>::new(1, 0);
+        assert_eq!(verbose.verbose_source(), None);
+        assert_eq!(verbose.quiet_source(), None);
+    }
+
+    #[test]
+    fn accumulate_subcommands() {
+        #[derive(Debug)]
+        struct Accumulate;
+
+        impl LogLevel for Accumulate {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Error
+            }
+
+            fn accumulate_subcommands() -> bool {
+                true
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<Accumulate>,
+            #[command(subcommand)]
+            command: Option<Command>,
+        }
+
+        #[derive(Debug, clap::Subcommand)]
+        enum Command {
+            Sub {
+                #[command(subcommand)]
+                command: Option<Inner>,
+            },
+        }
+
+        #[derive(Debug, clap::Subcommand)]
+        enum Inner {
+            Inner,
+        }
+
+        use clap::{CommandFactory, FromArgMatches};
+        let cmd = Verbosity::<Accumulate>::augment_subcommands(Cli::command());
+        cmd.clone().debug_assert();
+        let parse = |args: &[&str]| {
+            let matches = cmd.clone().try_get_matches_from(args).unwrap();
+            Cli::from_arg_matches(&matches).unwrap().verbose
+        };
+
+        assert_eq!(
+            parse(&["cmd", "-v", "sub", "-vv"]).filter(),
+            VerbosityFilter::Debug
+        );
+        assert_eq!(
+            parse(&["cmd", "-v", "sub", "inner", "-v"]).filter(),
+            VerbosityFilter::Info
+        );
+        let verbose = parse(&["cmd", "sub", "-q"]);
+        assert_eq!(verbose.filter(), VerbosityFilter::Off);
+        assert_eq!(verbose.quiet_source(), Some(ValueSource::CommandLine));
+        assert_eq!(parse(&["cmd", "sub"]).filter(), VerbosityFilter::Error);
+    }
+
+    #[test]
+    fn cargo_term_env() {
+        #[derive(Debug)]
+        struct Cargo;
+
+        impl LogLevel for Cargo {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Warn
+            }
+
+            fn cargo_term_env() -> bool {
+                true
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<Cargo>,
+        }
+
+        use clap::CommandFactory;
+        let parse = |args: &[&str], vars: &[(&str, &str)]| {
+            let matches = Cli::command().try_get_matches_from(args).unwrap();
+            let mut verbose = Verbosity::<Cargo>::new(0, 0);
+            verbose.update_from_arg_matches_with_env(&matches, |name| {
+                vars.iter()
+                    .find(|(var, _)| *var == name)
+                    .map(|(_, value)| OsString::from(value))
+            });
+            verbose
+        };
+
+        let env = [
+            ("CARGO_TERM_VERBOSE", "true"),
+            ("CARGO_TERM_QUIET", "false"),
+        ];
+        let verbose = parse(&["cmd"], &env);
+        assert_eq!(verbose.filter(), VerbosityFilter::Info);
+        assert_eq!(verbose.verbose_source(), Some(ValueSource::EnvVariable));
+        assert_eq!(verbose.quiet_source(), Some(ValueSource::DefaultValue));
+        let verbose = parse(&["cmd", "-v"], &env);
+        assert_eq!(verbose.filter(), VerbosityFilter::Debug);
+        assert_eq!(verbose.verbose_source(), Some(ValueSource::CommandLine));
+
+        let verbose = parse(&["cmd"], &[("CARGO_TERM_QUIET", "true")]);
+        assert_eq!(verbose.filter(), VerbosityFilter::Error);
+        assert_eq!(parse(&["cmd"], &[]).filter(), VerbosityFilter::Warn);
+    }
+
+    #[test]
+    fn arg_ids() {
+        use clap::{Args, Command};
+        let cmd = Verbosity::<ErrorLevel>::augment_args(Command::new("cmd"));
+        let ids: Vec<_> = cmd
+            .get_arguments()
+            .map(|arg| arg.get_id().as_str())
+            .collect();
+        assert_eq!(
+            ids,
+            [<Verbosity>::VERBOSE_ID, <Verbosity>::QUIET_ID],
+            "ids are part of the public API"
+        );
+    }
+
+    #[test]
+    fn prefix() {
+        #[derive(Debug)]
+        struct Client;
+
+        impl LogLevel for Client {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Error
+            }
+
+            fn prefix() -> Option<&'static str> {
+                Some("client")
+            }
+
+            fn verbose_short() -> Option<char> {
+                None
+            }
+
+            fn quiet_short() -> Option<char> {
+                None
+            }
+        }
+
+        #[derive(Debug)]
+        struct Server;
+
+        impl LogLevel for Server {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Info
+            }
+
+            fn prefix() -> Option<&'static str> {
+                Some("server")
+            }
+
+            fn verbose_short() -> Option<char> {
+                None
+            }
+
+            fn quiet_short() -> Option<char> {
+                None
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            client: Verbosity<Client>,
+            #[command(flatten)]
+            server: Verbosity<Server>,
+        }
+
+        use clap::{CommandFactory, Parser};
+        Cli::command().debug_assert();
+        let cli = Cli::try_parse_from(["cmd", "--client-verbose", "--server-quiet"]).unwrap();
+        assert_eq!(cli.client.filter(), VerbosityFilter::Warn);
+        assert_eq!(cli.server.filter(), VerbosityFilter::Warn);
+        assert!(Cli::try_parse_from(["cmd", "--client-verbose", "--client-quiet"]).is_err());
+        assert!(Cli::try_parse_from(["cmd", "--verbose"]).is_err());
+    }
+
+    #[test]
+    fn flag_names() {
+        #[derive(Debug)]
+        struct Chatty;
+
+        impl LogLevel for Chatty {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Warn
+            }
+
+            fn verbose_long() -> &'static str {
+                "chatty"
+            }
+
+            fn verbose_short() -> Option<char> {
+                Some('c')
+            }
+
+            fn quiet_long() -> &'static str {
+                "hush"
+            }
+
+            fn quiet_short() -> Option<char> {
+                Some('s')
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<Chatty>,
+        }
+
+        use clap::{CommandFactory, Parser};
+        Cli::command().debug_assert();
+        let parse = |args: &[&str]| Cli::try_parse_from(args).map(|cli| cli.verbose.filter());
+        assert_eq!(parse(&["cmd", "-cc"]).unwrap(), VerbosityFilter::Debug);
+        assert_eq!(parse(&["cmd", "--chatty"]).unwrap(), VerbosityFilter::Info);
+        assert_eq!(parse(&["cmd", "-s"]).unwrap(), VerbosityFilter::Error);
+        assert_eq!(parse(&["cmd", "--hush"]).unwrap(), VerbosityFilter::Error);
+        assert!(parse(&["cmd", "--verbose"]).is_err());
+        assert!(parse(&["cmd", "-q"]).is_err());
+    }
+
+    #[test]
+    fn filter_for() {
+        #[derive(Debug)]
+        struct Steps;
+
+        impl LogLevel for Steps {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Warn
+            }
+
+            fn filter_for(verbose: u8, quiet: u8) -> VerbosityFilter {
+                match (verbose, quiet) {
+                    (0, 0) => VerbosityFilter::Warn,
+                    (0, _) => VerbosityFilter::Off,
+                    (1, _) => VerbosityFilter::Debug,
+                    _ => VerbosityFilter::Trace,
+                }
+            }
+        }
+
+        let filter = |verbose, quiet| Verbosity::<Steps>::new(verbose, quiet).filter();
+        assert_eq!(filter(0, 0), VerbosityFilter::Warn);
+        assert_eq!(filter(0, 1), VerbosityFilter::Off);
+        assert_eq!(filter(1, 0), VerbosityFilter::Debug);
+        assert_eq!(filter(2, 0), VerbosityFilter::Trace);
+        assert_eq!(
+            VerboseOnly::<Steps>::new(1).filter(),
+            VerbosityFilter::Debug
+        );
+        assert_eq!(QuietOnly::<Steps>::new(1).filter(), VerbosityFilter::Off);
+    }
+
+    #[test]
+    fn help_text() {
+        #[derive(Debug)]
+        struct Localized;
+
+        impl LogLevel for Localized {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Error
+            }
+
+            fn verbose_help_text() -> Option<StyledStr> {
+                Some(format!("Mehr {}", "Ausgabe").into())
+            }
+
+            fn quiet_long_help_text() -> Option<StyledStr> {
+                Some(format!("Weniger {}", "Ausgabe").into())
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<Localized>,
+        }
+
+        use clap::CommandFactory;
+        let help = Cli::command().render_help().to_string();
+        assert!(help.contains("Mehr Ausgabe"), "{help}");
+        assert!(help.contains("Decrease logging verbosity"), "{help}");
+        let help = Cli::command().render_long_help().to_string();
+        assert!(help.contains("Mehr Ausgabe"), "{help}");
+        assert!(help.contains("-v = warn"), "{help}");
+        assert!(help.contains("Weniger Ausgabe"), "{help}");
+        assert!(!help.contains("-q = off"), "{help}");
+    }
+
+    #[test]
+    fn level_table() {
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<InfoLevel>,
+        }
+
+        use clap::CommandFactory;
+        let help = Cli::command().render_long_help().to_string();
+        assert!(help.contains("-v = debug, -vv = trace"), "{help}");
+        assert!(
+            help.contains("-q = warn, -qq = error, -qqq = off"),
+            "{help}"
+        );
+        assert!(!Cli::command().render_help().to_string().contains("-vv"));
+
+        assert_eq!(
+            super::level_table::<TraceLevel>(None, "verbose", |n| TraceLevel::filter_for(n, 0)),
+            None
+        );
+        assert_eq!(
+            super::level_table::<JumpToDebugLevel>(None, "verbose", |n| {
+                JumpToDebugLevel::filter_for(n, 0)
+            })
+            .unwrap(),
+            "--verbose = debug, --verbose x2 = trace"
+        );
+    }
+
+    #[test]
+    fn max_filter() {
+        #[derive(Debug)]
+        struct Capped;
+
+        impl LogLevel for Capped {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Info
+            }
+
+            fn max_filter() -> VerbosityFilter {
+                VerbosityFilter::Debug
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<Capped>,
+        }
+
+        use clap::{CommandFactory, Parser};
+        let parse = |args: &[&str]| Cli::try_parse_from(args).unwrap().verbose.filter();
+        assert_eq!(parse(&["cmd", "-v"]), VerbosityFilter::Debug);
+        assert_eq!(parse(&["cmd", "-vvv"]), VerbosityFilter::Debug);
+        assert_eq!(parse(&["cmd", "-q"]), VerbosityFilter::Warn);
+
+        let help = Cli::command().render_help().to_string();
+        assert!(
+            help.contains("Increase logging verbosity (up to debug)"),
+            "{help}"
+        );
+    }
+
+    #[test]
+    fn min_filter() {
+        #[derive(Debug)]
+        struct Floor;
+
+        impl LogLevel for Floor {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Warn
+            }
+
+            fn min_filter() -> VerbosityFilter {
+                VerbosityFilter::Error
+            }
+
+            fn silent_long() -> Option<&'static str> {
+                Some("silent")
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<Floor>,
+        }
+
+        use clap::{CommandFactory, Parser};
+        let parse = |args: &[&str]| Cli::try_parse_from(args).unwrap().verbose.filter();
+        assert_eq!(parse(&["cmd", "-q"]), VerbosityFilter::Error);
+        assert_eq!(parse(&["cmd", "-qqq"]), VerbosityFilter::Error);
+        assert_eq!(parse(&["cmd", "--silent"]), VerbosityFilter::Off);
+
+        let help = Cli::command().render_help().to_string();
+        assert!(
+            help.contains("Decrease logging verbosity (down to error)"),
+            "{help}"
+        );
+    }
+
+    #[test]
+    fn aliases() {
+        #[derive(Debug)]
+        struct Aliased;
+
+        impl LogLevel for Aliased {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Error
+            }
+
+            fn verbose_aliases() -> &'static [&'static str] {
+                &["loud"]
+            }
+
+            fn quiet_visible_aliases() -> &'static [&'static str] {
+                &["silence"]
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<Aliased>,
+        }
+
+        use clap::{CommandFactory, Parser};
+        Cli::command().debug_assert();
+        let parse = |args: &[&str]| Cli::try_parse_from(args).unwrap().verbose.filter();
+        assert_eq!(
+            parse(&["cmd", "--loud", "--verbose"]),
+            VerbosityFilter::Info
+        );
+        assert_eq!(parse(&["cmd", "--silence"]), VerbosityFilter::Off);
+
+        let help = Cli::command().render_help().to_string();
+        assert!(!help.contains("loud"), "{help}");
+        assert!(help.contains("silence"), "{help}");
+    }
+
+    #[test]
+    fn long_only() {
+        #[derive(Debug)]
+        struct LongOnly;
+
+        impl LogLevel for LongOnly {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Error
+            }
+
+            fn verbose_short() -> Option<char> {
+                None
+            }
+
+            fn quiet_short() -> Option<char> {
+                None
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<LongOnly>,
+
+            /// Application-specific flags
+            #[arg(short)]
+            v: bool,
+            #[arg(short)]
+            q: bool,
+        }
+
+        use clap::{CommandFactory, Parser};
+        Cli::command().debug_assert();
+        let cli = Cli::try_parse_from(["cmd", "-v", "--verbose", "--verbose"]).unwrap();
+        assert_eq!(cli.verbose.filter(), VerbosityFilter::Info);
+        assert!(cli.v);
+        let cli = Cli::try_parse_from(["cmd", "-q", "--quiet"]).unwrap();
+        assert_eq!(cli.verbose.filter(), VerbosityFilter::Off);
+        assert!(cli.q);
+    }
+
+    #[test]
+    fn allow_verbose_and_quiet() {
+        #[derive(Debug)]
+        struct Both;
+
+        impl LogLevel for Both {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Error
+            }
+
+            fn allow_verbose_and_quiet() -> bool {
+                true
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<Both>,
+        }
+
+        use clap::{CommandFactory, Parser};
+        Cli::command().debug_assert();
+        let parse = |args: &[&str]| Cli::try_parse_from(args).unwrap().verbose.filter();
+        assert_eq!(parse(&["cmd", "-q", "-vv"]), VerbosityFilter::Warn);
+        assert_eq!(parse(&["cmd", "-vv", "-qqq"]), VerbosityFilter::Off);
+        assert_eq!(parse(&["cmd", "-v", "-q"]), VerbosityFilter::Error);
+    }
+
+    #[test]
+    fn help_heading() {
+        #[derive(Debug)]
+        struct Logging;
+
+        impl LogLevel for Logging {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Error
+            }
+
+            fn help_heading() -> Option<&'static str> {
+                Some("Logging")
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<Logging>,
+            #[arg(long)]
+            json: bool,
+        }
+
+        use clap::{Args, Command, CommandFactory};
+        let help = Cli::command().render_help().to_string();
+        let logging = help.find("Logging:").unwrap();
+        let options = help.find("Options:").unwrap();
+        let verbose = help.find("--verbose").unwrap();
+        let json = help.find("--json").unwrap();
+        assert!(
+            options < json && json < logging && logging < verbose,
+            "{help}"
+        );
+
+        let mut cmd =
+            Verbosity::<ErrorLevel>::augment_args(Command::new("cmd").next_help_heading("Parent"));
+        let help = cmd.render_help().to_string();
+        assert!(
+            help.find("Parent:").unwrap() < help.find("--verbose").unwrap(),
+            "{help}"
+        );
+    }
+
+    #[test]
+    fn silent() {
+        #[derive(Debug)]
+        struct Silent;
+
+        impl LogLevel for Silent {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Debug
+            }
+
+            fn silent_long() -> Option<&'static str> {
+                Some("silent")
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<Silent>,
+        }
+
+        use clap::Parser;
+        let cli = Cli::try_parse_from(["cmd", "--silent"]).unwrap();
+        assert_eq!(cli.verbose.filter(), VerbosityFilter::Off);
+        assert!(cli.verbose.is_present());
+        assert!(cli.verbose.is_silent());
+        let cli = Cli::try_parse_from(["cmd", "-q", "--silent"]).unwrap();
+        assert_eq!(cli.verbose.filter(), VerbosityFilter::Off);
+        assert!(Cli::try_parse_from(["cmd", "-v", "--silent"]).is_err());
+        let cli = Cli::try_parse_from(["cmd", "-q"]).unwrap();
+        assert_eq!(cli.verbose.filter(), VerbosityFilter::Info);
+
+        use clap::{Args, Command, FromArgMatches};
+        let cmd = Verbosity::<ErrorLevel>::augment_args(Command::new("cmd"));
+        assert!(cmd.get_arguments().all(|arg| arg.get_id() != SILENT_ID));
+        let matches = cmd.try_get_matches_from(["cmd", "-q"]).unwrap();
+        let verbosity = Verbosity::<ErrorLevel>::from_arg_matches(&matches).unwrap();
+        assert_eq!(verbosity.filter(), VerbosityFilter::Off);
+    }
+
+    #[test]
+    fn display_order() {
+        #[derive(Debug)]
+        struct Last;
+
+        impl LogLevel for Last {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Error
+            }
+
+            fn display_order() -> Option<usize> {
+                Some(usize::MAX)
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<Last>,
+            #[arg(long)]
+            json: bool,
+        }
+
+        use clap::CommandFactory;
+        let help = Cli::command().render_help().to_string();
+        let json = help.find("--json").unwrap();
+        assert!(json < help.find("--verbose").unwrap(), "{help}");
+        assert!(json < help.find("--quiet").unwrap(), "{help}");
+    }
+
+    #[test]
+    fn profiles() {
+        #[derive(Debug)]
+        struct Profiles;
+
+        impl LogLevel for Profiles {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Warn
+            }
+
+            fn profiles() -> &'static [(&'static str, VerbosityFilter)] {
+                &[
+                    ("dev", VerbosityFilter::Debug),
+                    ("prod", VerbosityFilter::Error),
+                ]
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<Profiles>,
+        }
+
+        use clap::{CommandFactory, Parser};
+        Cli::command().debug_assert();
+        let parse = |args: &[&str]| Cli::try_parse_from(args).unwrap().verbose;
+        assert_eq!(parse(&["cmd"]).filter(), VerbosityFilter::Warn);
+        assert_eq!(parse(&["cmd"]).profile(), None);
+        let dev = parse(&["cmd", "--log-profile", "dev"]);
+        assert_eq!(dev.filter(), VerbosityFilter::Debug);
+        assert_eq!(dev.profile(), Some("dev"));
+        assert_eq!(
+            parse(&["cmd", "--log-profile=prod", "-vv"]).filter(),
+            VerbosityFilter::Info
+        );
+        assert!(Cli::try_parse_from(["cmd", "--log-profile", "staging"]).is_err());
 
-#[cfg(feature = "log")]
-pub mod log;
-#[cfg(feature = "tracing")]
-pub mod tracing;
+        use clap::Args;
+        let cmd = Verbosity::<ErrorLevel>::augment_args(clap::Command::new("cmd"));
+        assert!(cmd
+            .try_get_matches_from(["cmd", "--log-profile", "dev"])
+            .is_err());
+    }
 
-/// Logging flags to `#[command(flatten)]` into your CLI
-#[derive(clap::Args, Debug, Clone, Copy, Default)]
-#[command(about = None, long_about = None)]
-pub struct Verbosity<L: LogLevel = ErrorLevel> {
-    #[arg(
-        long,
-        short = 'v',
-        action = clap::ArgAction::Count,
-        global = true,
-        help = L::verbose_help(),
-        long_help = L::verbose_long_help(),
-    )]
-    verbose: u8,
+    #[test]
+    fn negations() {
+        #[derive(Debug)]
+        struct Negations;
 
-    #[arg(
-        long,
-        short = 'q',
-        action = clap::ArgAction::Count,
-        global = true,
-        help = L::quiet_help(),
-        long_help = L::quiet_long_help(),
-        conflicts_with = "verbose",
-    )]
-    quiet: u8,
+        impl LogLevel for Negations {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Warn
+            }
 
-    #[arg(skip)]
-    phantom: std::marker::PhantomData<L>,
-}
+            fn negations() -> bool {
+                true
+            }
+        }
 
-impl<L: LogLevel> Verbosity<L> {
-    /// Create a new verbosity instance by explicitly setting the values
-    pub fn new(verbose: u8, quiet: u8) -> Self {
-        Verbosity {
-            verbose,
-            quiet,
-            phantom: std::marker::PhantomData,
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<Negations>,
         }
-    }
 
-    /// Whether any verbosity flags (either `--verbose` or `--quiet`)
-    /// are present on the command line.
-    pub fn is_present(&self) -> bool {
-        self.verbose != 0 || self.quiet != 0
-    }
+        use clap::{CommandFactory, Parser};
+        Cli::command().debug_assert();
+        let parse = |args: &[&str]| Cli::try_parse_from(args).unwrap().verbose.filter();
+        assert_eq!(
+            parse(&["cmd", "-vv", "--no-verbose"]),
+            VerbosityFilter::Warn
+        );
+        assert_eq!(parse(&["cmd", "--no-verbose", "-v"]), VerbosityFilter::Info);
+        assert_eq!(parse(&["cmd", "-q", "--no-quiet"]), VerbosityFilter::Warn);
+        assert_eq!(
+            parse(&["cmd", "-v", "--no-verbose", "-q"]),
+            VerbosityFilter::Error
+        );
+        assert!(Cli::try_parse_from(["cmd", "--no-verbose", "-v", "-q"]).is_err());
 
-    /// If the user requested complete silence (i.e. not just no-logging).
-    pub fn is_silent(&self) -> bool {
-        self.filter() == VerbosityFilter::Off
+        let help = Cli::command().render_help().to_string();
+        assert!(help.contains("--no-verbose"), "{help}");
+        use clap::Args;
+        let cmd = Verbosity::<ErrorLevel>::augment_args(clap::Command::new("cmd"));
+        assert!(cmd.try_get_matches_from(["cmd", "--no-verbose"]).is_err());
     }
 
-    /// Gets the filter that should be applied to the logger.
-    pub fn filter(&self) -> VerbosityFilter {
-        let offset = self.verbose as i16 - self.quiet as i16;
-        L::default_filter().with_offset(offset)
-    }
-}
+    #[test]
+    fn hide() {
+        #[derive(Debug)]
+        struct Hidden;
 
-#[cfg(feature = "log")]
-impl<L: LogLevel> Verbosity<L> {
-    /// Get the log level.
-    ///
-    /// `None` means all output is disabled.
-    pub fn log_level(&self) -> Option<log::Level> {
-        self.filter().into()
-    }
+        impl LogLevel for Hidden {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Error
+            }
 
-    /// Get the log level filter.
-    pub fn log_level_filter(&self) -> log::LevelFilter {
-        self.filter().into()
-    }
-}
+            fn hide() -> bool {
+                true
+            }
+        }
 
-#[cfg(feature = "tracing")]
-impl<L: LogLevel> Verbosity<L> {
-    /// Get the tracing level.
-    ///
-    /// `None` means all output is disabled.
-    pub fn tracing_level(&self) -> Option<tracing_core::Level> {
-        self.filter().into()
-    }
+        #[derive(Debug)]
+        struct LongHelpOnly;
 
-    /// Get the tracing level filter.
-    pub fn tracing_level_filter(&self) -> tracing_core::LevelFilter {
-        self.filter().into()
-    }
-}
+        impl LogLevel for LongHelpOnly {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Error
+            }
 
-impl<L: LogLevel> fmt::Display for Verbosity<L> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.filter().fmt(f)
-    }
-}
+            fn hide_short_help() -> bool {
+                true
+            }
+        }
 
-/// Customize the default log-level and associated help
-pub trait LogLevel {
-    /// Baseline level before applying `--verbose` and `--quiet`
-    fn default_filter() -> VerbosityFilter;
+        use clap::{Args, Command};
+        let mut cmd = Verbosity::<Hidden>::augment_args(Command::new("cmd"));
+        cmd.clone().debug_assert();
+        assert!(!cmd.render_help().to_string().contains("--verbose"));
+        assert!(!cmd.render_long_help().to_string().contains("--quiet"));
+        assert!(cmd.try_get_matches_from_mut(["cmd", "-v"]).is_ok());
 
-    /// Short-help message for `--verbose`
-    fn verbose_help() -> Option<&'static str> {
-        Some("Increase logging verbosity")
+        let mut cmd = Verbosity::<LongHelpOnly>::augment_args(Command::new("cmd"));
+        assert!(!cmd.render_help().to_string().contains("--verbose"));
+        assert!(cmd.render_long_help().to_string().contains("--verbose"));
+        assert!(cmd.render_long_help().to_string().contains("--quiet"));
     }
 
-    /// Long-help message for `--verbose`
-    fn verbose_long_help() -> Option<&'static str> {
-        None
-    }
+    #[test]
+    fn env() {
+        // Each case reads its own variable, set once and never removed, as tests run in parallel
+        macro_rules! env_level {
+            ($name:ident, $verbose:expr, $quiet:expr) => {
+                #[derive(Debug)]
+                struct $name;
 
-    /// Short-help message for `--quiet`
-    fn quiet_help() -> Option<&'static str> {
-        Some("Decrease logging verbosity")
-    }
+                impl LogLevel for $name {
+                    fn default_filter() -> VerbosityFilter {
+                        VerbosityFilter::Error
+                    }
 
-    /// Long-help message for `--quiet`
-    fn quiet_long_help() -> Option<&'static str> {
-        None
-    }
-}
+                    fn verbose_env() -> Option<&'static str> {
+                        $verbose
+                    }
 
-/// A representation of the log level filter.
-///
-/// Used to calculate the log level and filter.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum VerbosityFilter {
-    Off,
-    Error,
-    Warn,
-    Info,
-    Debug,
-    Trace,
-}
+                    fn quiet_env() -> Option<&'static str> {
+                        $quiet
+                    }
+                }
+            };
+        }
+        env_level!(Verbose, Some("CLAP_VERBOSITY_FLAG_TEST_ENV_VERBOSE"), None);
+        env_level!(Invalid, Some("CLAP_VERBOSITY_FLAG_TEST_ENV_INVALID"), None);
+        env_level!(Quiet, None, Some("CLAP_VERBOSITY_FLAG_TEST_ENV_QUIET"));
+        env_level!(
+            Unset,
+            Some("CLAP_VERBOSITY_FLAG_TEST_ENV_UNSET_VERBOSE"),
+            Some("CLAP_VERBOSITY_FLAG_TEST_ENV_UNSET_QUIET")
+        );
+        std::env::set_var("CLAP_VERBOSITY_FLAG_TEST_ENV_VERBOSE", "2");
+        std::env::set_var("CLAP_VERBOSITY_FLAG_TEST_ENV_INVALID", "loud");
+        std::env::set_var("CLAP_VERBOSITY_FLAG_TEST_ENV_QUIET", "1");
 
-impl VerbosityFilter {
-    /// Apply an offset to the filter level.
-    ///
-    /// Negative values will decrease the verbosity, while positive values will increase it.
-    fn with_offset(&self, offset: i16) -> VerbosityFilter {
-        let value = match self {
-            Self::Off => 0_i16,
-            Self::Error => 1,
-            Self::Warn => 2,
-            Self::Info => 3,
-            Self::Debug => 4,
-            Self::Trace => 5,
-        };
-        match value.saturating_add(offset) {
-            i16::MIN..=0 => Self::Off,
-            1 => Self::Error,
-            2 => Self::Warn,
-            3 => Self::Info,
-            4 => Self::Debug,
-            5..=i16::MAX => Self::Trace,
+        fn parse<L: LogLevel>(args: &[&str]) -> Result<Verbosity<L>, clap::Error> {
+            use clap::{Args, Command, FromArgMatches};
+            let cmd = Verbosity::<L>::augment_args(Command::new("cmd"));
+            cmd.clone().debug_assert();
+            Verbosity::from_arg_matches(&cmd.try_get_matches_from(args)?)
         }
+
+        let verbose = parse::<Verbose>(&["cmd"]).unwrap();
+        assert_eq!(verbose.filter(), VerbosityFilter::Info);
+        assert_eq!(verbose.verbose_source(), Some(ValueSource::EnvVariable));
+        let verbose = parse::<Verbose>(&["cmd", "-v"]).unwrap();
+        assert_eq!(verbose.filter(), VerbosityFilter::Warn);
+        assert_eq!(verbose.verbose_source(), Some(ValueSource::CommandLine));
+        assert!(parse::<Invalid>(&["cmd"]).is_err());
+        assert_eq!(
+            parse::<Quiet>(&["cmd"]).unwrap().filter(),
+            VerbosityFilter::Off
+        );
+        assert_eq!(
+            parse::<Unset>(&["cmd"]).unwrap().filter(),
+            VerbosityFilter::Error
+        );
     }
-}
 
-impl fmt::Display for VerbosityFilter {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Off => write!(f, "off"),
-            Self::Error => write!(f, "error"),
-            Self::Warn => write!(f, "warn"),
-            Self::Info => write!(f, "info"),
-            Self::Debug => write!(f, "debug"),
-            Self::Trace => write!(f, "trace"),
+    #[test]
+    fn numeric_values() {
+        #[derive(Debug)]
+        struct Numeric;
+
+        impl LogLevel for Numeric {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Error
+            }
+
+            fn numeric_values() -> bool {
+                true
+            }
         }
-    }
-}
 
-/// Default to [`VerbosityFilter::Error`]
-#[derive(Copy, Clone, Debug, Default)]
-pub struct ErrorLevel;
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<Numeric>,
+            #[command(subcommand)]
+            command: Option<Command>,
+        }
 
-impl LogLevel for ErrorLevel {
-    fn default_filter() -> VerbosityFilter {
-        VerbosityFilter::Error
+        #[derive(Debug, clap::Subcommand)]
+        enum Command {
+            Sub,
+        }
+
+        use clap::{CommandFactory, Parser};
+        Cli::command().debug_assert();
+        let parse = |args: &[&str]| Cli::try_parse_from(args).unwrap().verbose;
+        assert_eq!(parse(&["cmd"]).filter(), VerbosityFilter::Error);
+        assert_eq!(
+            parse(&["cmd"]).verbose_source(),
+            Some(ValueSource::DefaultValue)
+        );
+        assert_eq!(parse(&["cmd", "-vv"]).filter(), VerbosityFilter::Info);
+        assert_eq!(
+            parse(&["cmd", "--verbose=3"]).filter(),
+            VerbosityFilter::Debug
+        );
+        assert_eq!(
+            parse(&["cmd", "-v", "-v=3"]).filter(),
+            VerbosityFilter::Trace
+        );
+        assert_eq!(parse(&["cmd", "-q=2"]).filter(), VerbosityFilter::Off);
+        assert_eq!(parse(&["cmd", "-v", "sub"]).filter(), VerbosityFilter::Warn);
+        assert_eq!(parse(&["cmd", "-v=255", "-v"]).verbose, 255);
+        assert!(Cli::try_parse_from(["cmd", "--verbose=loud"]).is_err());
     }
-}
 
-/// Default to [`VerbosityFilter::Warn`]
-#[derive(Copy, Clone, Debug, Default)]
-pub struct WarnLevel;
+    #[test]
+    fn not_global() {
+        #[derive(Debug)]
+        struct TopLevel;
 
-impl LogLevel for WarnLevel {
-    fn default_filter() -> VerbosityFilter {
-        VerbosityFilter::Warn
-    }
-}
+        impl LogLevel for TopLevel {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Error
+            }
 
-/// Default to [`VerbosityFilter::Info`]
-#[derive(Copy, Clone, Debug, Default)]
-pub struct InfoLevel;
+            fn global() -> bool {
+                false
+            }
+        }
 
-impl LogLevel for InfoLevel {
-    fn default_filter() -> VerbosityFilter {
-        VerbosityFilter::Info
-    }
-}
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: Verbosity<TopLevel>,
+            #[command(subcommand)]
+            command: Option<Command>,
+        }
 
-/// Default to [`VerbosityFilter::Debug`]
-#[derive(Copy, Clone, Debug, Default)]
-pub struct DebugLevel;
+        #[derive(Debug, clap::Subcommand)]
+        enum Command {
+            Sub,
+        }
 
-impl LogLevel for DebugLevel {
-    fn default_filter() -> VerbosityFilter {
-        VerbosityFilter::Debug
+        use clap::{CommandFactory, Parser};
+        Cli::command().debug_assert();
+        let cli = Cli::try_parse_from(["cmd", "-v", "sub"]).unwrap();
+        assert_eq!(cli.verbose.filter(), VerbosityFilter::Warn);
+        assert!(Cli::try_parse_from(["cmd", "sub", "-v"]).is_err());
     }
-}
 
-/// Default to [`VerbosityFilter::Trace`]
-#[derive(Copy, Clone, Debug, Default)]
-pub struct TraceLevel;
+    #[test]
+    fn all() {
+        assert_eq!(VerbosityFilter::iter().len(), 6);
+        assert!(VerbosityFilter::ALL
+            .windows(2)
+            .all(|pair| pair[0] < pair[1]));
+        assert_eq!(VerbosityFilter::iter().next(), Some(VerbosityFilter::Off));
+        assert_eq!(VerbosityFilter::iter().last(), Some(VerbosityFilter::Trace));
+    }
 
-impl LogLevel for TraceLevel {
-    fn default_filter() -> VerbosityFilter {
-        VerbosityFilter::Trace
+    #[test]
+    fn numeric() {
+        for (value, filter) in VerbosityFilter::iter().enumerate() {
+            let value = u8::try_from(value).unwrap();
+            assert_eq!(u8::from(filter), value);
+            assert_eq!(VerbosityFilter::try_from(value), Ok(filter));
+        }
+        let err = VerbosityFilter::try_from(6).unwrap_err();
+        assert_eq!(err.value(), 6);
+        assert_eq!(err.to_string(), "verbosity 6 is out of range 0..=5");
     }
-}
 
-/// Default to [`VerbosityFilter::Off`] (no logging)
-#[derive(Copy, Clone, Debug, Default)]
-pub struct OffLevel;
+    #[test]
+    fn python_level() {
+        for filter in VerbosityFilter::iter() {
+            assert_eq!(
+                VerbosityFilter::from_python_level(filter.to_python_level()),
+                filter
+            );
+        }
+        assert_eq!(
+            VerbosityFilter::from_python_level(0),
+            VerbosityFilter::Trace
+        );
+        assert_eq!(
+            VerbosityFilter::from_python_level(35),
+            VerbosityFilter::Error
+        );
+        assert_eq!(VerbosityFilter::from_python_level(51), VerbosityFilter::Off);
+    }
 
-impl LogLevel for OffLevel {
-    fn default_filter() -> VerbosityFilter {
-        VerbosityFilter::Off
+    #[test]
+    fn value_enum() {
+        use clap::ValueEnum;
+        for filter in VerbosityFilter::value_variants() {
+            assert_eq!(
+                VerbosityFilter::from_str(filter.as_str(), false),
+                Ok(*filter)
+            );
+        }
+        assert_eq!(
+            VerbosityFilter::from_str("DEBUG", true),
+            Ok(VerbosityFilter::Debug)
+        );
+        assert!(VerbosityFilter::from_str("loud", true).is_err());
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    #[test]
+    fn filter_name() {
+        struct Localized;
+
+        impl LogLevel for Localized {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Warn
+            }
+
+            fn filter_name(filter: VerbosityFilter) -> &'static str {
+                match filter {
+                    VerbosityFilter::Warn => "avviso",
+                    _ => "altro",
+                }
+            }
+        }
+
+        assert_eq!(Verbosity::<Localized>::new(0, 0).to_string(), "avviso");
+        assert_eq!(Verbosity::<Localized>::new(1, 0).to_string(), "altro");
+        assert_eq!(VerbosityFilter::Warn.to_string(), "warn");
+        assert_eq!(Verbosity::<WarnLevel>::new(0, 0).to_string(), "warn");
+    }
 
     #[test]
-    fn verify_app() {
+    fn group_id() {
         #[derive(Debug, clap::Parser)]
         struct Cli {
             #[command(flatten)]
             verbose: Verbosity,
+
+            #[arg(long, conflicts_with = <Verbosity>::GROUP_ID)]
+            json: bool,
+
+            #[arg(long, requires = "Verbosity")]
+            explain: bool,
         }
 
-        use clap::CommandFactory;
-        Cli::command().debug_assert();
+        use clap::Parser;
+        assert!(Cli::try_parse_from(["cmd", "--explain", "-v"]).is_ok());
+        assert!(Cli::try_parse_from(["cmd", "--explain"]).is_err());
+        assert!(Cli::try_parse_from(["cmd", "--json"]).is_ok());
+        assert!(Cli::try_parse_from(["cmd", "-v"]).is_ok());
+        assert!(Cli::try_parse_from(["cmd", "--json", "-v"]).is_err());
+        assert!(Cli::try_parse_from(["cmd", "--json", "-q"]).is_err());
     }
 
     /// Asserts that the filter is correct for the given verbosity and quiet values.
@@ -453,4 +3232,50 @@ mod test {
             assert_filter::<TraceLevel>(verbose, quiet, expected_filter);
         }
     }
+
+    #[test]
+    fn verbosity_const_level() {
+        assert_filter::<Level<0>>(0, 0, VerbosityFilter::Off);
+        assert_filter::<Level<0>>(1, 0, VerbosityFilter::Error);
+        assert_filter::<Level<2>>(0, 0, VerbosityFilter::Warn);
+        assert_filter::<Level<2>>(0, 1, VerbosityFilter::Error);
+        assert_filter::<Level<5>>(0, 0, VerbosityFilter::Trace);
+        assert_filter::<Level<{ u8::MAX }>>(0, 1, VerbosityFilter::Debug);
+    }
+
+    #[test]
+    fn verbosity_jump_to_debug_level() {
+        let tests = [
+            (0, 0, VerbosityFilter::Error),
+            (1, 0, VerbosityFilter::Debug),
+            (2, 0, VerbosityFilter::Trace),
+            (255, 0, VerbosityFilter::Trace),
+            (0, 1, VerbosityFilter::Off),
+            (0, 255, VerbosityFilter::Off),
+            (2, 1, VerbosityFilter::Debug),
+            (255, 255, VerbosityFilter::Error),
+        ];
+
+        for (verbose, quiet, expected_filter) in tests {
+            assert_filter::<JumpToDebugLevel>(verbose, quiet, expected_filter);
+        }
+    }
+
+    #[test]
+    fn verbosity_two_step_level() {
+        let tests = [
+            (0, 0, VerbosityFilter::Error),
+            (1, 0, VerbosityFilter::Info),
+            (2, 0, VerbosityFilter::Trace),
+            (3, 0, VerbosityFilter::Trace),
+            (255, 0, VerbosityFilter::Trace),
+            (0, 1, VerbosityFilter::Off),
+            (0, 255, VerbosityFilter::Off),
+            (255, 255, VerbosityFilter::Error),
+        ];
+
+        for (verbose, quiet, expected_filter) in tests {
+            assert_filter::<TwoStepLevel>(verbose, quiet, expected_filter);
+        }
+    }
 }
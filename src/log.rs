@@ -31,6 +31,13 @@ impl From<LevelFilter> for VerbosityFilter {
     }
 }
 
+/// See `From<VerbosityFilter> for Verbosity`
+impl<L: LogLevel> From<LevelFilter> for Verbosity<L> {
+    fn from(level: LevelFilter) -> Self {
+        VerbosityFilter::from(level).into()
+    }
+}
+
 impl From<VerbosityFilter> for Option<Level> {
     fn from(filter: VerbosityFilter) -> Self {
         match filter {
@@ -57,6 +64,28 @@ impl From<Option<Level>> for VerbosityFilter {
     }
 }
 
+impl From<Level> for VerbosityFilter {
+    fn from(level: Level) -> Self {
+        Some(level).into()
+    }
+}
+
+/// Lets a [`Verbosity`] be passed straight to a logger, e.g.
+/// ```rust,no_run
+/// # use clap::Parser;
+/// # use clap_verbosity_flag::Verbosity;
+/// #
+/// # /// Le CLI
+/// # #[derive(Debug, Parser)]
+/// # struct Cli {
+/// #     #[command(flatten)]
+/// #     verbose: Verbosity,
+/// # }
+/// let cli = Cli::parse();
+/// env_logger::Builder::new()
+///     .filter_level(cli.verbose.into())
+///     .init();
+/// ```
 impl<L: LogLevel> From<Verbosity<L>> for LevelFilter {
     fn from(v: Verbosity<L>) -> Self {
         v.log_level_filter()
@@ -73,6 +102,7 @@ impl<L: LogLevel> From<Verbosity<L>> for Option<Level> {
 mod tests {
     use super::*;
     use crate::{DebugLevel, ErrorLevel, InfoLevel, OffLevel, TraceLevel, Verbosity, WarnLevel};
+    use crate::{ShortcutVerbosity, VerboseOnly, VerbosityFilter, VerbosityLevel};
 
     #[test]
     fn log_level() {
@@ -142,4 +172,42 @@ mod tests {
         let v = Verbosity::<TraceLevel>::default();
         assert_eq!(LevelFilter::from(v), LevelFilter::Trace);
     }
+
+    #[test]
+    fn from_verbosity_level() {
+        let v = VerbosityLevel::<ErrorLevel>::new(VerbosityFilter::Off);
+        assert_eq!(Option::<Level>::from(v), None);
+        assert_eq!(LevelFilter::from(v), LevelFilter::Off);
+
+        let v = VerbosityLevel::<ErrorLevel>::new(VerbosityFilter::Debug);
+        assert_eq!(Option::<Level>::from(v), Some(Level::Debug));
+        assert_eq!(LevelFilter::from(v), LevelFilter::Debug);
+    }
+
+    #[test]
+    fn from_wrappers() {
+        let v = VerboseOnly::<ErrorLevel>::new(2);
+        assert_eq!(Option::<Level>::from(v), Some(Level::Info));
+        assert_eq!(LevelFilter::from(v), LevelFilter::Info);
+        assert_eq!(VerbosityFilter::from(v), VerbosityFilter::Info);
+
+        let v = ShortcutVerbosity::<ErrorLevel>::new(Verbosity::new(0, 0), true, false);
+        assert_eq!(LevelFilter::from(v), LevelFilter::Debug);
+    }
+
+    #[test]
+    fn into_verbosity() {
+        let v = Verbosity::<ErrorLevel>::from(LevelFilter::Debug);
+        assert_eq!(v.verbose(), 3);
+        assert_eq!(LevelFilter::from(v), LevelFilter::Debug);
+    }
+
+    #[test]
+    fn is_enabled() {
+        assert!(VerbosityFilter::Warn.is_enabled(Level::Error));
+        assert!(VerbosityFilter::Warn.is_enabled(Level::Warn));
+        assert!(!VerbosityFilter::Warn.is_enabled(Level::Trace));
+        assert!(!VerbosityFilter::Off.is_enabled(Level::Error));
+        assert!(VerbosityFilter::Trace.is_enabled(Level::Trace));
+    }
 }
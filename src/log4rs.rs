@@ -0,0 +1,83 @@
+//! Set up [`log4rs`] from the flags
+
+pub use log4rs::config::runtime::RootBuilder;
+pub use log4rs::config::{Config, Root};
+
+use crate::{LogLevel, Verbosity};
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Build the [`Root`] logger at the flags' level
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// use log4rs::append::console::ConsoleAppender;
+    /// use log4rs::config::{Appender, Config, Root};
+    ///
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// let stderr = ConsoleAppender::builder().build();
+    /// let config = Config::builder()
+    ///     .appender(Appender::builder().build("stderr", Box::new(stderr)))
+    ///     .build(cli.verbose.log4rs_root(Root::builder().appender("stderr")))
+    ///     .unwrap();
+    /// log4rs::init_config(config).unwrap();
+    /// ```
+    pub fn log4rs_root(&self, root: RootBuilder) -> Root {
+        root.build(self.log_level_filter())
+    }
+
+    /// Override the root level of an existing `config` with the flags
+    ///
+    /// Levels of named loggers are left as configured.
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// # fn load_config() -> log4rs::Config { unimplemented!() }
+    /// let cli = Cli::parse();
+    /// let config = cli.verbose.apply_to_log4rs(load_config());
+    /// log4rs::init_config(config).unwrap();
+    /// ```
+    pub fn apply_to_log4rs(&self, mut config: Config) -> Config {
+        config.root_mut().set_level(self.log_level_filter());
+        config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use log4rs::config::Logger;
+
+    use super::*;
+    use crate::ErrorLevel;
+
+    #[test]
+    fn log4rs_root() {
+        let root = Verbosity::<ErrorLevel>::new(2, 0).log4rs_root(Root::builder());
+        assert_eq!(root.level(), log::LevelFilter::Info);
+    }
+
+    #[test]
+    fn apply_to_log4rs() {
+        let config = Config::builder()
+            .logger(Logger::builder().build("hyper", log::LevelFilter::Warn))
+            .build(Root::builder().build(log::LevelFilter::Trace))
+            .unwrap();
+        let config = Verbosity::<ErrorLevel>::new(0, 1).apply_to_log4rs(config);
+        assert_eq!(config.root().level(), log::LevelFilter::Off);
+        assert_eq!(config.loggers()[0].level(), log::LevelFilter::Warn);
+    }
+}
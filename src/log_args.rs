@@ -0,0 +1,630 @@
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::fmt::format::{DefaultFields, Format, JsonFields, Pretty};
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
+use tracing_subscriber::fmt::{FormatEvent, FormatFields};
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::Registry;
+
+use crate::rotate::SizeRotatingFile;
+use crate::{ColorChoice, ErrorLevel, LogLevel, LogRotation, Timestamps, Verbosity};
+
+/// Logging options to `#[command(flatten)]` into your CLI
+///
+/// Along with the [`Verbosity`] flags, this provides:
+/// - `--log-target <stderr|stdout|file|syslog|journald>`, see [`LogTarget`]
+/// - `--log-file <PATH>` to append to a file instead of writing to stderr, or `--log-to-file` for
+///   [`LogArgs::default_file`] with the `directories` feature
+/// - `--log-rotate <never|hourly|daily|size:N>` and `--log-keep <N>` to rotate that file, see
+///   [`LogRotation`]
+/// - `--log-format <pretty|compact|json>`, see [`LogFormat`], or `--log-json` for short
+/// - `--log-color <auto|always|never>`, see [`ColorChoice`]
+/// - `--log-timestamps[=<none|seconds|millis|rfc3339|local>]` to prefix each line with the time,
+///   see [`Timestamps`]
+///
+/// [`LogArgs::init`] then sets up [`tracing_subscriber`] accordingly:
+/// ```rust,no_run
+/// # use clap::Parser;
+/// # use clap_verbosity_flag::LogArgs;
+/// #
+/// # /// Le CLI
+/// # #[derive(Debug, Parser)]
+/// # struct Cli {
+/// #     #[command(flatten)]
+/// #     log: LogArgs,
+/// # }
+/// let cli = Cli::parse();
+/// cli.log.init();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LogArgs<L: LogLevel = ErrorLevel> {
+    verbosity: Verbosity<L>,
+    target: Option<LogTarget>,
+    file: Option<PathBuf>,
+    rotation: LogRotation,
+    keep: Option<usize>,
+    format: LogFormat,
+    color: ColorChoice,
+    timestamps: Timestamps,
+}
+
+/// Where [`LogArgs::init`] sends log lines
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum LogTarget {
+    /// Standard error
+    #[default]
+    Stderr,
+    /// Standard output
+    Stdout,
+    /// The `--log-file`
+    File,
+    /// The local syslog daemon, on Unix
+    Syslog,
+    /// The systemd journal, with the `journald` feature
+    Journald,
+}
+
+/// How [`LogArgs::init`] formats each line
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum LogFormat {
+    /// Multi-line, human readable output
+    Pretty,
+    /// Single-line output
+    #[default]
+    Compact,
+    /// A JSON object per line
+    Json,
+}
+
+impl<L: LogLevel> LogArgs<L> {
+    /// The [`clap::ArgGroup`] containing the generated options, except for the [`Verbosity`] flags
+    pub const GROUP_ID: &'static str = "clap_log_args";
+
+    /// Create a new instance with `verbosity` and the default options
+    pub fn new(verbosity: Verbosity<L>) -> Self {
+        Self {
+            verbosity,
+            target: None,
+            file: None,
+            rotation: LogRotation::default(),
+            keep: None,
+            format: LogFormat::default(),
+            color: ColorChoice::default(),
+            timestamps: Timestamps::default(),
+        }
+    }
+
+    /// The `-v` and `-q` flags
+    pub fn verbosity(&self) -> &Verbosity<L> {
+        &self.verbosity
+    }
+
+    /// Where to send logs
+    ///
+    /// Without `--log-target`, this is [`LogTarget::File`] if a `--log-file` was passed, and
+    /// [`LogTarget::Stderr`] otherwise.
+    pub fn target(&self) -> LogTarget {
+        match (self.target, &self.file) {
+            (Some(target), _) => target,
+            (None, Some(_)) => LogTarget::File,
+            (None, None) => LogTarget::Stderr,
+        }
+    }
+
+    /// The file to append logs to, for [`LogTarget::File`]
+    ///
+    /// With `--log-to-file`, this is the resolved [`LogArgs::default_file`], e.g. to tell the
+    /// user where to look.
+    pub fn file(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+
+    /// Where `--log-to-file` appends logs: `<app>.log` in a per-user directory named after the
+    /// executable
+    ///
+    /// - Linux and other Unixes: the XDG state directory, e.g. `~/.local/state/<app>/`
+    /// - Windows: `%LOCALAPPDATA%\<app>\logs\`
+    /// - macOS: `~/Library/Logs/<app>/`
+    ///
+    /// `None` when there's no home directory.
+    #[cfg(feature = "directories")]
+    pub fn default_file() -> Option<PathBuf> {
+        let dirs = directories::BaseDirs::new()?;
+        Some(default_file_in(
+            std::env::consts::OS,
+            dirs.home_dir(),
+            dirs.state_dir(),
+            dirs.data_local_dir(),
+            &app_name(),
+        ))
+    }
+
+    /// When to start a new log file
+    pub fn rotation(&self) -> LogRotation {
+        self.rotation
+    }
+
+    /// How many log files to keep when rotating, including the current one, or all of them
+    pub fn keep(&self) -> Option<usize> {
+        self.keep
+    }
+
+    /// The line format
+    pub fn format(&self) -> LogFormat {
+        self.format
+    }
+
+    /// Whether to color the output
+    pub fn color(&self) -> ColorChoice {
+        self.color
+    }
+
+    /// How to prefix each line with the time
+    pub fn timestamps(&self) -> Timestamps {
+        self.timestamps
+    }
+
+    /// Install a [`tracing_subscriber`] following the options as the global default subscriber
+    ///
+    /// # Panics
+    ///
+    /// If the log file can't be opened, or a global default subscriber was already set, see
+    /// [`LogArgs::try_init`].
+    pub fn init(&self) {
+        self.try_init().expect("failed to initialize logging");
+    }
+
+    /// Install a [`tracing_subscriber`] following the options as the global default subscriber,
+    /// failing if the target can't be reached or a subscriber already is set
+    pub fn try_init(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        tracing_subscriber::registry()
+            .with(self.target_layer()?)
+            .with(self.verbosity.tracing_level_filter())
+            .try_init()?;
+        Ok(())
+    }
+
+    /// The layer writing to [`LogArgs::target`]
+    fn target_layer(&self) -> Result<BoxedLayer, Box<dyn std::error::Error + Send + Sync>> {
+        let layer = match self.target() {
+            LogTarget::Stderr => self.layer(
+                BoxMakeWriter::new(io::stderr),
+                self.color.should_color(&io::stderr()),
+            ),
+            LogTarget::Stdout => self.layer(
+                BoxMakeWriter::new(io::stdout),
+                self.color.should_color(&io::stdout()),
+            ),
+            LogTarget::File => {
+                let path = self.file.as_ref().ok_or("no log file to write to")?;
+                self.layer(self.file_writer(path)?, false)
+            }
+            #[cfg(unix)]
+            LogTarget::Syslog => {
+                let writer = crate::syslog::SyslogWriter::connect(&app_name())?;
+                self.layer(BoxMakeWriter::new(writer), false)
+            }
+            #[cfg(not(unix))]
+            LogTarget::Syslog => return Err("syslog is only supported on Unix".into()),
+            #[cfg(feature = "journald")]
+            LogTarget::Journald => Box::new(self.verbosity.journald_layer()?),
+            #[cfg(not(feature = "journald"))]
+            LogTarget::Journald => return Err("journald requires the `journald` feature".into()),
+        };
+        Ok(layer)
+    }
+
+    /// A writer appending to `path`, rotated as requested
+    fn file_writer(
+        &self,
+        path: &Path,
+    ) -> Result<BoxMakeWriter, Box<dyn std::error::Error + Send + Sync>> {
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        fs::create_dir_all(dir)?;
+        let rotation = match self.rotation {
+            LogRotation::Never => {
+                let file = File::options().create(true).append(true).open(path)?;
+                return Ok(BoxMakeWriter::new(Arc::new(file)));
+            }
+            LogRotation::Size(max_bytes) => {
+                let file = SizeRotatingFile::open(path, max_bytes, self.keep)?;
+                return Ok(BoxMakeWriter::new(Arc::new(file)));
+            }
+            LogRotation::Hourly => Rotation::HOURLY,
+            LogRotation::Daily => Rotation::DAILY,
+        };
+        let name = path.file_name().ok_or("log file has no name")?;
+        let mut builder = RollingFileAppender::builder()
+            .rotation(rotation)
+            .filename_prefix(name.to_string_lossy());
+        if let Some(keep) = self.keep {
+            builder = builder.max_log_files(keep);
+        }
+        Ok(BoxMakeWriter::new(builder.build(dir)?))
+    }
+
+    /// The formatting layer, writing to `writer`
+    fn layer(&self, writer: BoxMakeWriter, ansi: bool) -> BoxedLayer {
+        let layer = tracing_subscriber::fmt::layer()
+            .with_writer(writer)
+            .with_ansi(ansi);
+        match self.format {
+            LogFormat::Pretty => self.timed::<Pretty, _>(layer.pretty()),
+            LogFormat::Compact => self.timed::<DefaultFields, _>(layer.compact()),
+            LogFormat::Json => self.timed::<JsonFields, _>(layer.json()),
+        }
+    }
+
+    /// Box `layer`, with the requested timestamps
+    fn timed<N, E>(&self, layer: FmtLayer<N, Format<E>>) -> BoxedLayer
+    where
+        N: for<'w> FormatFields<'w> + Send + Sync + 'static,
+        E: Send + Sync + 'static,
+        Format<E, Timestamps>: FormatEvent<Registry, N>,
+        Format<E, ()>: FormatEvent<Registry, N>,
+    {
+        if self.timestamps.is_enabled() {
+            Box::new(layer.with_timer(self.timestamps))
+        } else {
+            Box::new(layer.without_time())
+        }
+    }
+}
+
+/// The executable's name, to tag logs with
+#[cfg(any(unix, feature = "directories"))]
+fn app_name() -> String {
+    let exe = std::env::current_exe().unwrap_or_default();
+    exe.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "app".to_owned())
+}
+
+/// [`LogArgs::default_file`] on `os`, with the directories passed in
+#[cfg(feature = "directories")]
+fn default_file_in(
+    os: &str,
+    home: &Path,
+    state: Option<&Path>,
+    local_data: &Path,
+    app: &str,
+) -> PathBuf {
+    let dir = match os {
+        "macos" => home.join("Library").join("Logs").join(app),
+        "windows" => local_data.join(app).join("logs"),
+        _ => state.unwrap_or(local_data).join(app),
+    };
+    dir.join(format!("{app}.log"))
+}
+
+type FmtLayer<N, E> = tracing_subscriber::fmt::Layer<Registry, N, E, BoxMakeWriter>;
+
+type BoxedLayer = Box<dyn tracing_subscriber::Layer<Registry> + Send + Sync>;
+
+impl<L: LogLevel> clap::FromArgMatches for LogArgs<L> {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut args = Self::new(Verbosity::new(0, 0));
+        args.update_from_arg_matches(matches)?;
+        Ok(args)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        self.verbosity.update_from_arg_matches(matches)?;
+        if let Some(target) = matches.get_one::<LogTarget>(&crate::prefixed::<L>(TARGET_ID)) {
+            self.target = Some(*target);
+        }
+        if let Some(file) = matches.get_one::<PathBuf>(&crate::prefixed::<L>(FILE_ID)) {
+            self.file = Some(file.clone());
+        }
+        #[cfg(feature = "directories")]
+        if matches.get_flag(&crate::prefixed::<L>(TO_FILE_ID)) {
+            let file = Self::default_file().ok_or_else(|| {
+                clap::Error::raw(
+                    clap::error::ErrorKind::Io,
+                    "no home directory to put the log file in\n",
+                )
+            })?;
+            self.file = Some(file);
+        }
+        if let Some(rotation) = matches.get_one::<LogRotation>(&crate::prefixed::<L>(ROTATE_ID)) {
+            self.rotation = *rotation;
+        }
+        if let Some(keep) = matches.get_one::<usize>(&crate::prefixed::<L>(KEEP_ID)) {
+            self.keep = Some(*keep);
+        }
+        if let Some(format) = matches.get_one::<LogFormat>(&crate::prefixed::<L>(FORMAT_ID)) {
+            self.format = *format;
+        }
+        if matches.get_flag(&crate::prefixed::<L>(JSON_ID)) {
+            self.format = LogFormat::Json;
+        }
+        if let Some(color) = matches.get_one::<ColorChoice>(&crate::prefixed::<L>(COLOR_ID)) {
+            self.color = *color;
+        }
+        if let Some(timestamps) =
+            matches.get_one::<Timestamps>(&crate::prefixed::<L>(TIMESTAMPS_ID))
+        {
+            self.timestamps = *timestamps;
+        }
+        Ok(())
+    }
+}
+
+impl<L: LogLevel> clap::Args for LogArgs<L> {
+    fn group_id() -> Option<clap::Id> {
+        Some(clap::Id::from(crate::prefixed::<L>(Self::GROUP_ID)))
+    }
+
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        let option = |id: &str| {
+            let id = crate::prefixed::<L>(id);
+            clap::Arg::new(id.clone()).long(id).global(L::global())
+        };
+        let file_group = crate::prefixed::<L>(FILE_GROUP_ID);
+        let mut args = vec![
+            option(TARGET_ID)
+                .value_name("TARGET")
+                .value_parser(clap::value_parser!(LogTarget))
+                .requires_if("file", file_group.clone())
+                .help("Where to send log lines"),
+            option(FILE_ID)
+                .value_name("PATH")
+                .value_parser(clap::value_parser!(PathBuf))
+                .help("Append logs to a file instead of stderr"),
+        ];
+        #[cfg(feature = "directories")]
+        args.push(
+            option(TO_FILE_ID)
+                .action(clap::ArgAction::SetTrue)
+                .help("Append logs to a file in the per-user log directory instead of stderr"),
+        );
+        let file_args: Vec<_> = args[1..].iter().map(|arg| arg.get_id().clone()).collect();
+        args.extend([
+            option(ROTATE_ID)
+                .value_name("WHEN")
+                .value_parser(clap::value_parser!(LogRotation))
+                .requires(file_group.clone())
+                .help("When to start a new log file: never, hourly, daily or size:<N>[KB|MB|GB]"),
+            option(KEEP_ID)
+                .value_name("N")
+                .value_parser(clap::builder::RangedU64ValueParser::<usize>::new().range(1..))
+                .requires(file_group.clone())
+                .help("How many rotated log files to keep, including the current one"),
+            option(FORMAT_ID)
+                .value_name("FORMAT")
+                .value_parser(clap::value_parser!(LogFormat))
+                .help("Format of log lines"),
+            option(JSON_ID)
+                .action(clap::ArgAction::SetTrue)
+                .conflicts_with(crate::prefixed::<L>(FORMAT_ID))
+                .help("Write log lines as JSON, same as --log-format json"),
+            option(COLOR_ID)
+                .value_name("WHEN")
+                .value_parser(clap::value_parser!(ColorChoice))
+                .help("When to color log lines"),
+            crate::timestamps::timestamps_arg(crate::prefixed::<L>(TIMESTAMPS_ID))
+                .global(L::global()),
+        ]);
+        let group = clap::ArgGroup::new(crate::prefixed::<L>(Self::GROUP_ID))
+            .multiple(true)
+            .args(args.iter().map(|arg| arg.get_id().clone()));
+        let file_group = clap::ArgGroup::new(file_group).args(file_args);
+        <Verbosity<L> as clap::Args>::augment_args(cmd)
+            .group(group)
+            .group(file_group)
+            .args(args.into_iter().map(crate::help_placement::<L>))
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+const TARGET_ID: &str = "log-target";
+const FILE_ID: &str = "log-file";
+#[cfg(feature = "directories")]
+const TO_FILE_ID: &str = "log-to-file";
+const FILE_GROUP_ID: &str = "clap_log_file";
+const ROTATE_ID: &str = "log-rotate";
+const KEEP_ID: &str = "log-keep";
+const FORMAT_ID: &str = "log-format";
+const JSON_ID: &str = "log-json";
+const COLOR_ID: &str = "log-color";
+const TIMESTAMPS_ID: &str = "log-timestamps";
+
+#[cfg(test)]
+mod tests {
+    use clap::{CommandFactory as _, Parser};
+
+    use super::*;
+    use crate::tracing_subscriber::TestWriter;
+    use crate::{InfoLevel, VerbosityFilter};
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(flatten)]
+        log: LogArgs<InfoLevel>,
+    }
+
+    #[test]
+    fn verify_app() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn defaults() {
+        let cli = Cli::try_parse_from(["cmd"]).unwrap();
+        assert_eq!(cli.log.verbosity().filter(), VerbosityFilter::Info);
+        assert_eq!(cli.log.target(), LogTarget::Stderr);
+        assert_eq!(cli.log.file(), None);
+        assert_eq!(cli.log.format(), LogFormat::Compact);
+        assert_eq!(cli.log.color(), ColorChoice::Auto);
+        assert_eq!(cli.log.timestamps(), Timestamps::None);
+    }
+
+    #[test]
+    fn options() {
+        let cli = Cli::try_parse_from([
+            "cmd",
+            "-v",
+            "--log-file",
+            "app.log",
+            "--log-format",
+            "json",
+            "--log-color",
+            "never",
+            "--log-timestamps",
+        ])
+        .unwrap();
+        assert_eq!(cli.log.verbosity().filter(), VerbosityFilter::Debug);
+        assert_eq!(cli.log.target(), LogTarget::File);
+        assert_eq!(cli.log.file(), Some(Path::new("app.log")));
+        assert_eq!(cli.log.format(), LogFormat::Json);
+        assert_eq!(cli.log.color(), ColorChoice::Never);
+        assert_eq!(cli.log.timestamps(), Timestamps::Rfc3339);
+        assert!(Cli::try_parse_from(["cmd", "--log-format", "xml"]).is_err());
+    }
+
+    #[test]
+    fn target() {
+        let cli = Cli::try_parse_from(["cmd", "--log-target", "stdout"]).unwrap();
+        assert_eq!(cli.log.target(), LogTarget::Stdout);
+        let cli = Cli::try_parse_from(["cmd", "--log-target", "syslog", "--log-file", "app.log"])
+            .unwrap();
+        assert_eq!(cli.log.target(), LogTarget::Syslog);
+        assert!(Cli::try_parse_from(["cmd", "--log-target", "file"]).is_err());
+        assert!(Cli::try_parse_from(["cmd", "--log-target", "eventlog"]).is_err());
+
+        #[cfg(not(feature = "journald"))]
+        {
+            let cli = Cli::try_parse_from(["cmd", "--log-target", "journald"]).unwrap();
+            assert!(cli.log.target_layer().is_err());
+        }
+    }
+
+    #[test]
+    fn rotation() {
+        let cli = Cli::try_parse_from(["cmd"]).unwrap();
+        assert_eq!(cli.log.rotation(), LogRotation::Never);
+        assert_eq!(cli.log.keep(), None);
+
+        let cli = Cli::try_parse_from([
+            "cmd",
+            "--log-file",
+            "app.log",
+            "--log-rotate",
+            "size:10MB",
+            "--log-keep",
+            "5",
+        ])
+        .unwrap();
+        assert_eq!(cli.log.rotation(), LogRotation::Size(10 << 20));
+        assert_eq!(cli.log.keep(), Some(5));
+
+        assert!(Cli::try_parse_from(["cmd", "--log-rotate", "daily"]).is_err());
+        assert!(Cli::try_parse_from(["cmd", "--log-keep", "5"]).is_err());
+        assert!(Cli::try_parse_from(["cmd", "--log-file", "a", "--log-keep", "0"]).is_err());
+        assert!(Cli::try_parse_from(["cmd", "--log-file", "a", "--log-rotate", "weekly"]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "directories")]
+    fn log_to_file() {
+        let cli = Cli::try_parse_from(["cmd", "--log-to-file", "--log-rotate", "daily"]).unwrap();
+        assert_eq!(cli.log.target(), LogTarget::File);
+        assert_eq!(
+            cli.log.file(),
+            LogArgs::<InfoLevel>::default_file().as_deref()
+        );
+        assert!(cli
+            .log
+            .file()
+            .unwrap()
+            .ends_with(format!("{}.log", app_name())));
+        assert!(Cli::try_parse_from(["cmd", "--log-to-file", "--log-file", "app.log"]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "directories")]
+    fn default_file_in() {
+        let home = Path::new("/home/me");
+        let state = Path::new("/home/me/.local/state");
+        let local = Path::new("/home/me/.local/share");
+        assert_eq!(
+            super::default_file_in("linux", home, Some(state), local, "app"),
+            Path::new("/home/me/.local/state/app/app.log")
+        );
+        assert_eq!(
+            super::default_file_in("freebsd", home, None, local, "app"),
+            Path::new("/home/me/.local/share/app/app.log")
+        );
+        assert_eq!(
+            super::default_file_in("macos", home, None, local, "app"),
+            Path::new("/home/me/Library/Logs/app/app.log")
+        );
+        assert_eq!(
+            super::default_file_in("windows", home, None, local, "app"),
+            local.join("app").join("logs").join("app.log")
+        );
+    }
+
+    #[test]
+    fn log_json() {
+        let cli = Cli::try_parse_from(["cmd", "--log-json"]).unwrap();
+        assert_eq!(cli.log.format(), LogFormat::Json);
+        assert!(Cli::try_parse_from(["cmd", "--log-json", "--log-format", "pretty"]).is_err());
+    }
+
+    fn render(args: &[&str]) -> String {
+        let cli = Cli::try_parse_from(args).unwrap();
+        let buffer = TestWriter::default();
+        let subscriber = tracing_subscriber::registry()
+            .with(cli.log.layer(BoxMakeWriter::new(buffer.clone()), false))
+            .with(cli.log.verbosity().tracing_level_filter());
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(code = 7, "shown");
+            tracing::debug!("hidden");
+        });
+        buffer.contents()
+    }
+
+    #[test]
+    fn compact() {
+        assert_eq!(
+            render(&["cmd"]),
+            " INFO clap_verbosity_flag::log_args::tests: shown code=7\n"
+        );
+    }
+
+    #[test]
+    fn json() {
+        let line = render(&["cmd", "--log-format", "json"]);
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["level"], "INFO");
+        assert_eq!(value["fields"]["message"], "shown");
+        assert_eq!(value["fields"]["code"], 7);
+        assert!(value.get("timestamp").is_none(), "{line}");
+    }
+
+    #[test]
+    fn timestamps() {
+        let line = render(&["cmd", "--log-timestamps"]);
+        assert!(!line.starts_with(' '), "{line}");
+        assert!(line.contains(" INFO "), "{line}");
+
+        let line = render(&["cmd", "--log-timestamps=seconds"]);
+        let (time, rest) = line.split_once(' ').unwrap();
+        assert_eq!(time.len(), "2024-05-01T12:00:00Z".len(), "{line}");
+        assert!(rest.starts_with(" INFO "), "{line}");
+
+        let line = render(&["cmd", "--log-timestamps=none"]);
+        assert!(line.starts_with(" INFO "), "{line}");
+    }
+}
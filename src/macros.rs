@@ -0,0 +1,84 @@
+/// The accessors, [`Display`][std::fmt::Display] and `From` impls every flattenable wrapper shares
+///
+/// `$ty` must be generic over a single [`LogLevel`][crate::LogLevel] and have a
+/// `filter(&self) -> VerbosityFilter` method.
+macro_rules! filter_accessors {
+    ($ty:ident) => {
+        impl<L: $crate::LogLevel> $ty<L> {
+            /// If the user requested complete silence (i.e. not just no-logging).
+            pub fn is_silent(&self) -> bool {
+                self.filter() == $crate::VerbosityFilter::Off
+            }
+        }
+
+        #[cfg(feature = "log")]
+        impl<L: $crate::LogLevel> $ty<L> {
+            /// Get the log level.
+            ///
+            /// `None` means all output is disabled.
+            pub fn log_level(&self) -> Option<log::Level> {
+                self.filter().into()
+            }
+
+            /// Get the log level filter.
+            pub fn log_level_filter(&self) -> log::LevelFilter {
+                self.filter().into()
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        impl<L: $crate::LogLevel> $ty<L> {
+            /// Get the tracing level.
+            ///
+            /// `None` means all output is disabled.
+            pub fn tracing_level(&self) -> Option<tracing_core::Level> {
+                self.filter().into()
+            }
+
+            /// Get the tracing level filter.
+            pub fn tracing_level_filter(&self) -> tracing_core::LevelFilter {
+                self.filter().into()
+            }
+        }
+
+        impl<L: $crate::LogLevel> std::fmt::Display for $ty<L> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(L::filter_name(self.filter()))
+            }
+        }
+
+        impl<L: $crate::LogLevel> From<$ty<L>> for $crate::VerbosityFilter {
+            fn from(v: $ty<L>) -> Self {
+                v.filter()
+            }
+        }
+
+        #[cfg(feature = "log")]
+        impl<L: $crate::LogLevel> From<$ty<L>> for log::LevelFilter {
+            fn from(v: $ty<L>) -> Self {
+                v.log_level_filter()
+            }
+        }
+
+        #[cfg(feature = "log")]
+        impl<L: $crate::LogLevel> From<$ty<L>> for Option<log::Level> {
+            fn from(v: $ty<L>) -> Self {
+                v.log_level()
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        impl<L: $crate::LogLevel> From<$ty<L>> for tracing_core::LevelFilter {
+            fn from(v: $ty<L>) -> Self {
+                v.tracing_level_filter()
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        impl<L: $crate::LogLevel> From<$ty<L>> for Option<tracing_core::Level> {
+            fn from(v: $ty<L>) -> Self {
+                v.tracing_level()
+            }
+        }
+    };
+}
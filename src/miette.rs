@@ -0,0 +1,107 @@
+//! Scale [`miette`] report detail with the flags
+
+use miette::MietteHandlerOpts;
+
+use crate::{LogLevel, Verbosity};
+
+impl<L: LogLevel> Verbosity<L> {
+    /// [`MietteHandlerOpts`] showing more of each report the more `--verbose` is passed
+    ///
+    /// - By default, reports only show the top-level diagnostic with a line of context
+    /// - From `-vv`, reports also show the cause chain and more context
+    ///
+    /// Diagnostics have no backtraces, see [`Verbosity::install_miette_hook`] for panics.
+    pub fn miette_hook_opts(&self) -> MietteHandlerOpts {
+        let opts = MietteHandlerOpts::new();
+        if self.offset() >= 2 {
+            opts.with_cause_chain().context_lines(3)
+        } else {
+            opts.without_cause_chain().context_lines(1)
+        }
+    }
+
+    /// Install [`Verbosity::miette_hook_opts`] as the report hook
+    ///
+    /// From `-vvv`, panics are also reported by miette, with a backtrace, see
+    /// [`miette::set_panic_hook`].
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// fn main() -> miette::Result<()> {
+    ///     let cli = Cli::parse();
+    ///     cli.verbose.install_miette_hook()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn install_miette_hook(&self) -> Result<(), miette::InstallError> {
+        let opts = self.miette_hook_opts();
+        miette::set_hook(Box::new(move |_| Box::new(opts.clone().build())))?;
+        if self.offset() >= 3 {
+            miette::set_panic_hook();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use miette::{Diagnostic, ReportHandler as _};
+
+    use super::*;
+    use crate::ErrorLevel;
+
+    #[derive(Debug, Diagnostic)]
+    struct Outer(Inner);
+
+    impl fmt::Display for Outer {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("outer")
+        }
+    }
+
+    impl std::error::Error for Outer {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[derive(Debug)]
+    struct Inner;
+
+    impl fmt::Display for Inner {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("inner")
+        }
+    }
+
+    impl std::error::Error for Inner {}
+
+    struct Render(MietteHandlerOpts);
+
+    impl fmt::Display for Render {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let handler = self.0.clone().force_narrated(true).build();
+            handler.debug(&Outer(Inner), f)
+        }
+    }
+
+    #[test]
+    fn miette_hook_opts() {
+        let quiet = Render(Verbosity::<ErrorLevel>::new(1, 0).miette_hook_opts()).to_string();
+        assert!(quiet.contains("outer"), "{quiet}");
+        assert!(!quiet.contains("inner"), "{quiet}");
+
+        let detailed = Render(Verbosity::<ErrorLevel>::new(2, 0).miette_hook_opts()).to_string();
+        assert!(detailed.contains("inner"), "{detailed}");
+    }
+}
@@ -0,0 +1,214 @@
+use clap::parser::ValueSource;
+
+use crate::{ErrorLevel, LogLevel, VerbosityFilter};
+
+/// `-v`/`--verbose` without `--quiet`, to `#[command(flatten)]` into your CLI
+///
+/// For tools where `-q` is already taken or quieting below the default isn't meaningful.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VerboseOnly<L: LogLevel = ErrorLevel> {
+    verbose: u8,
+    source: Option<ValueSource>,
+    phantom: std::marker::PhantomData<L>,
+}
+
+impl<L: LogLevel> VerboseOnly<L> {
+    /// The [`clap::ArgGroup`] containing the generated flag
+    pub const GROUP_ID: &'static str = "clap_verbosity_verbose";
+
+    /// Create a new instance by explicitly setting the count
+    pub fn new(verbose: u8) -> Self {
+        Self {
+            verbose,
+            source: None,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Where the `--verbose` count came from.
+    ///
+    /// See [`Verbosity::verbose_source`][crate::Verbosity::verbose_source].
+    pub fn source(&self) -> Option<ValueSource> {
+        self.source
+    }
+
+    /// Whether `--verbose` is present on the command line.
+    pub fn is_present(&self) -> bool {
+        self.verbose != 0
+    }
+
+    /// Gets the filter that should be applied to the logger.
+    pub fn filter(&self) -> VerbosityFilter {
+        crate::clamp::<L>(L::filter_for(self.verbose, 0))
+    }
+}
+
+filter_accessors!(VerboseOnly);
+
+impl<L: LogLevel> clap::FromArgMatches for VerboseOnly<L> {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut verbosity = Self::new(0);
+        verbosity.update_from_arg_matches(matches)?;
+        Ok(verbosity)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        if let Some((verbose, source)) =
+            crate::get_count::<L>(matches, &crate::prefixed::<L>(crate::VERBOSE_ID))
+        {
+            self.verbose = verbose;
+            self.source = source;
+        }
+        Ok(())
+    }
+}
+
+impl<L: LogLevel> clap::Args for VerboseOnly<L> {
+    fn group_id() -> Option<clap::Id> {
+        Some(clap::Id::from(crate::prefixed::<L>(Self::GROUP_ID)))
+    }
+
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        cmd.group(crate::flag_group::<L>(Self::GROUP_ID, &[crate::VERBOSE_ID]))
+            .arg(crate::verbose_arg::<L>())
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+/// `-q`/`--quiet` without `--verbose`, to `#[command(flatten)]` into your CLI
+///
+/// For chatty tools that default to a high level and only need a way to reduce output.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QuietOnly<L: LogLevel = ErrorLevel> {
+    quiet: u8,
+    source: Option<ValueSource>,
+    phantom: std::marker::PhantomData<L>,
+}
+
+impl<L: LogLevel> QuietOnly<L> {
+    /// The [`clap::ArgGroup`] containing the generated flag
+    pub const GROUP_ID: &'static str = "clap_verbosity_quiet";
+
+    /// Create a new instance by explicitly setting the count
+    pub fn new(quiet: u8) -> Self {
+        Self {
+            quiet,
+            source: None,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Where the `--quiet` count came from.
+    ///
+    /// See [`Verbosity::quiet_source`][crate::Verbosity::quiet_source].
+    pub fn source(&self) -> Option<ValueSource> {
+        self.source
+    }
+
+    /// Whether `--quiet` is present on the command line.
+    pub fn is_present(&self) -> bool {
+        self.quiet != 0
+    }
+
+    /// Gets the filter that should be applied to the logger.
+    pub fn filter(&self) -> VerbosityFilter {
+        crate::clamp::<L>(L::filter_for(0, self.quiet))
+    }
+}
+
+filter_accessors!(QuietOnly);
+
+impl<L: LogLevel> clap::FromArgMatches for QuietOnly<L> {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut verbosity = Self::new(0);
+        verbosity.update_from_arg_matches(matches)?;
+        Ok(verbosity)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        if let Some((quiet, source)) =
+            crate::get_count::<L>(matches, &crate::prefixed::<L>(crate::QUIET_ID))
+        {
+            self.quiet = quiet;
+            self.source = source;
+        }
+        Ok(())
+    }
+}
+
+impl<L: LogLevel> clap::Args for QuietOnly<L> {
+    fn group_id() -> Option<clap::Id> {
+        Some(clap::Id::from(crate::prefixed::<L>(Self::GROUP_ID)))
+    }
+
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        cmd.group(crate::flag_group::<L>(Self::GROUP_ID, &[crate::QUIET_ID]))
+            .arg(crate::quiet_arg::<L>())
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::{CommandFactory, Parser};
+
+    use super::*;
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(flatten)]
+        verbose: VerboseOnly,
+
+        /// Only print the result
+        #[arg(short, long)]
+        quiet: bool,
+    }
+
+    #[test]
+    fn verify_app() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn parse() {
+        let cli = Cli::try_parse_from(["cmd"]).unwrap();
+        assert_eq!(cli.verbose.filter(), VerbosityFilter::Error);
+        assert!(!cli.verbose.is_present());
+
+        let cli = Cli::try_parse_from(["cmd", "-vv", "-q"]).unwrap();
+        assert_eq!(cli.verbose.filter(), VerbosityFilter::Info);
+        assert!(cli.verbose.is_present());
+        assert!(cli.quiet);
+    }
+
+    #[test]
+    fn quiet_only() {
+        #[derive(Debug, Parser)]
+        struct Cli {
+            #[command(flatten)]
+            quiet: QuietOnly<crate::InfoLevel>,
+
+            /// Show the full report
+            #[arg(short, long)]
+            verbose: bool,
+        }
+
+        Cli::command().debug_assert();
+
+        let cli = Cli::try_parse_from(["cmd"]).unwrap();
+        assert_eq!(cli.quiet.filter(), VerbosityFilter::Info);
+        assert!(!cli.quiet.is_present());
+
+        let cli = Cli::try_parse_from(["cmd", "-qq", "-v"]).unwrap();
+        assert_eq!(cli.quiet.filter(), VerbosityFilter::Error);
+        assert_eq!(VerbosityFilter::from(cli.quiet), VerbosityFilter::Error);
+        assert!(cli.quiet.is_present());
+        assert!(cli.verbose);
+    }
+}
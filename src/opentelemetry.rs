@@ -0,0 +1,126 @@
+//! [OpenTelemetry](https://opentelemetry.io/docs/specs/otel/logs/data-model/#field-severitynumber)
+//! log severity support
+
+use std::fmt;
+
+use crate::VerbosityFilter;
+
+/// An OpenTelemetry severity, from the least to the most severe
+///
+/// Each severity covers a range of four `SeverityNumber`s, this is the first of its range.
+/// OpenTelemetry has no off level, so [`VerbosityFilter::Off`] maps to [`Severity::Fatal`], letting
+/// an exporter configured from it drop everything but fatal records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Trace = 1,
+    Debug = 5,
+    Info = 9,
+    Warn = 13,
+    Error = 17,
+    Fatal = 21,
+}
+
+impl Severity {
+    /// The `SeverityNumber`
+    pub fn number(&self) -> u8 {
+        *self as u8
+    }
+
+    /// The severity covering a `SeverityNumber`, e.g. `INFO2` (10) is [`Severity::Info`]
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1..=4 => Some(Self::Trace),
+            5..=8 => Some(Self::Debug),
+            9..=12 => Some(Self::Info),
+            13..=16 => Some(Self::Warn),
+            17..=20 => Some(Self::Error),
+            21..=24 => Some(Self::Fatal),
+            _ => None,
+        }
+    }
+
+    /// The `SeverityText` short name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Trace => "TRACE",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+            Self::Fatal => "FATAL",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<VerbosityFilter> for Severity {
+    fn from(filter: VerbosityFilter) -> Self {
+        match filter {
+            VerbosityFilter::Off => Self::Fatal,
+            VerbosityFilter::Error => Self::Error,
+            VerbosityFilter::Warn => Self::Warn,
+            VerbosityFilter::Info => Self::Info,
+            VerbosityFilter::Debug => Self::Debug,
+            VerbosityFilter::Trace => Self::Trace,
+        }
+    }
+}
+
+impl From<Severity> for VerbosityFilter {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Trace => Self::Trace,
+            Severity::Debug => Self::Debug,
+            Severity::Info => Self::Info,
+            Severity::Warn => Self::Warn,
+            Severity::Error | Severity::Fatal => Self::Error,
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+impl From<log::Level> for Severity {
+    fn from(level: log::Level) -> Self {
+        VerbosityFilter::from(Some(level)).into()
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl From<tracing_core::Level> for Severity {
+    fn from(level: tracing_core::Level) -> Self {
+        VerbosityFilter::from(Some(level)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_filter() {
+        assert_eq!(Severity::from(VerbosityFilter::Off), Severity::Fatal);
+        assert_eq!(Severity::from(VerbosityFilter::Error), Severity::Error);
+        assert_eq!(Severity::from(VerbosityFilter::Info), Severity::Info);
+        assert_eq!(Severity::from(VerbosityFilter::Trace), Severity::Trace);
+        assert_eq!(Severity::Warn.number(), 13);
+        assert_eq!(Severity::Fatal.to_string(), "FATAL");
+    }
+
+    #[test]
+    fn from_number() {
+        assert_eq!(Severity::from_number(0), None);
+        assert_eq!(Severity::from_number(10), Some(Severity::Info));
+        assert_eq!(Severity::from_number(24), Some(Severity::Fatal));
+        assert_eq!(Severity::from_number(25), None);
+        for filter in VerbosityFilter::iter().skip(1) {
+            let severity = Severity::from(filter);
+            assert_eq!(Severity::from_number(severity.number()), Some(severity));
+            assert_eq!(VerbosityFilter::from(severity), filter);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::ffi::OsStr;
+
+use clap::builder::{PossibleValue, TypedValueParser};
+use clap::error::ErrorKind;
+
+use crate::VerbosityFilter;
+
+/// Parse a [`VerbosityFilter`] from its name, ignoring case
+///
+/// Common aliases are accepted too, e.g. `warning` and `err`, as well as the numbers `0` (off)
+/// through `5` (trace).  Only the canonical names are listed in help.
+/// ```rust
+/// # use clap::Parser;
+/// # use clap_verbosity_flag::{VerbosityFilter, VerbosityFilterParser};
+/// #[derive(Debug, Parser)]
+/// struct Cli {
+///     #[arg(long, value_parser = VerbosityFilterParser::new())]
+///     log_level: Option<VerbosityFilter>,
+/// }
+///
+/// let cli = Cli::parse_from(["cmd", "--log-level", "Warning"]);
+/// assert_eq!(cli.log_level, Some(VerbosityFilter::Warn));
+/// ```
+#[derive(Clone, Debug)]
+pub struct VerbosityFilterParser {
+    filters: Vec<VerbosityFilter>,
+}
+
+impl VerbosityFilterParser {
+    /// Accept every [`VerbosityFilter`]
+    pub fn new() -> Self {
+        Self::with_filters(VerbosityFilter::iter())
+    }
+
+    /// Only accept `filters`
+    pub(crate) fn with_filters(filters: impl IntoIterator<Item = VerbosityFilter>) -> Self {
+        Self {
+            filters: filters.into_iter().collect(),
+        }
+    }
+}
+
+impl Default for VerbosityFilterParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypedValueParser for VerbosityFilterParser {
+    type Value = VerbosityFilter;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, clap::Error> {
+        if let Some(value) = value.to_str() {
+            let found = self
+                .filters
+                .iter()
+                .find(|filter| possible_value(**filter).matches(value, true));
+            if let Some(filter) = found {
+                return Ok(*filter);
+            }
+        }
+        let arg = arg
+            .map(ToString::to_string)
+            .unwrap_or_else(|| "...".to_owned());
+        let names = self
+            .filters
+            .iter()
+            .map(|filter| filter.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let message = format!(
+            "invalid value '{}' for '{arg}'\n  [possible values: {names}]\n",
+            value.to_string_lossy()
+        );
+        Err(clap::Error::raw(ErrorKind::InvalidValue, message).with_cmd(cmd))
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(
+            self.filters.iter().map(|filter| possible_value(*filter)),
+        ))
+    }
+}
+
+/// Find the filter named `value`, ignoring case and accepting aliases
+pub(crate) fn parse_filter(value: &str) -> Option<VerbosityFilter> {
+    VerbosityFilter::iter().find(|filter| possible_value(*filter).matches(value, true))
+}
+
+fn possible_value(filter: VerbosityFilter) -> PossibleValue {
+    let aliases: &[&'static str] = match filter {
+        VerbosityFilter::Off => &["0"],
+        VerbosityFilter::Error => &["err", "1"],
+        VerbosityFilter::Warn => &["warning", "2"],
+        VerbosityFilter::Info => &["information", "3"],
+        VerbosityFilter::Debug => &["4"],
+        VerbosityFilter::Trace => &["5"],
+    };
+    PossibleValue::new(filter.as_str()).aliases(aliases.iter().copied())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let cmd = clap::Command::new("cmd");
+        let parser = VerbosityFilterParser::new();
+        let parse = |value: &str| parser.parse_ref(&cmd, None, OsStr::new(value));
+        assert_eq!(parse("debug").unwrap(), VerbosityFilter::Debug);
+        assert_eq!(parse("TRACE").unwrap(), VerbosityFilter::Trace);
+        assert_eq!(parse("Warning").unwrap(), VerbosityFilter::Warn);
+        assert_eq!(parse("err").unwrap(), VerbosityFilter::Error);
+        assert_eq!(parse("0").unwrap(), VerbosityFilter::Off);
+        assert_eq!(parse("3").unwrap(), VerbosityFilter::Info);
+        assert_eq!(parse("Information").unwrap(), VerbosityFilter::Info);
+        assert!(parse("6").is_err());
+        let err = parse("loud").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert!(
+            err.to_string()
+                .contains("[possible values: off, error, warn, info, debug, trace]"),
+            "{err}"
+        );
+
+        let parser = VerbosityFilterParser::with_filters([VerbosityFilter::Error]);
+        assert!(parser.parse_ref(&cmd, None, OsStr::new("warn")).is_err());
+    }
+}
@@ -0,0 +1,67 @@
+//! Set up [`pretty_env_logger`] from the flags
+
+// pretty_env_logger builds on its own version of env_logger, re-exported here so the builder can
+// be customized without depending on the matching version directly.
+pub use pretty_env_logger::env_logger::Builder;
+
+use crate::{LogLevel, Verbosity};
+
+impl<L: LogLevel> Verbosity<L> {
+    /// A [`Builder`] with `pretty_env_logger`'s colored format at the flags' level
+    pub fn pretty_env_logger_builder(&self) -> Builder {
+        let mut builder = pretty_env_logger::formatted_builder();
+        builder.filter_level(self.log_level_filter());
+        builder
+    }
+
+    /// Install [`Verbosity::pretty_env_logger_builder`] as the global logger
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose.init_pretty_env_logger();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If a global logger was already installed, see [`Verbosity::try_init_pretty_env_logger`].
+    pub fn init_pretty_env_logger(&self) {
+        self.pretty_env_logger_builder().init();
+    }
+
+    /// Install [`Verbosity::pretty_env_logger_builder`] as the global logger, failing if one
+    /// already is
+    pub fn try_init_pretty_env_logger(&self) -> Result<(), log::SetLoggerError> {
+        self.pretty_env_logger_builder().try_init()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use log::Log as _;
+
+    use super::*;
+    use crate::InfoLevel;
+
+    #[test]
+    fn pretty_env_logger_builder() {
+        let logger = Verbosity::<InfoLevel>::new(0, 1)
+            .pretty_env_logger_builder()
+            .build();
+        assert_eq!(logger.filter(), log::LevelFilter::Warn);
+        assert!(!logger.enabled(
+            &log::Metadata::builder()
+                .level(log::Level::Info)
+                .target("")
+                .build()
+        ));
+    }
+}
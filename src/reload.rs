@@ -0,0 +1,109 @@
+//! Change the level of a [`tracing_subscriber`] stack after startup
+
+use tracing_core::LevelFilter;
+use tracing_subscriber::reload::{self, Handle};
+
+use crate::{LogLevel, Verbosity, VerbosityFilter};
+
+impl<L: LogLevel> Verbosity<L> {
+    /// A layer filtering at the flags' level, and a handle to change that level later
+    ///
+    /// e.g. for a daemon to get louder on `SIGUSR1`:
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// use tracing_subscriber::prelude::*;
+    ///
+    /// /// Le CLI
+    /// #[derive(Debug, Parser)]
+    /// struct Cli {
+    ///     #[command(flatten)]
+    ///     verbose: Verbosity,
+    /// }
+    ///
+    /// let cli = Cli::parse();
+    /// let (filter, handle) = cli.verbose.reloadable();
+    /// tracing_subscriber::registry()
+    ///     .with(filter)
+    ///     .with(tracing_subscriber::fmt::layer())
+    ///     .init();
+    ///
+    /// // later
+    /// handle.increase().unwrap();
+    /// ```
+    pub fn reloadable<S>(&self) -> (reload::Layer<LevelFilter, S>, VerbosityReloadHandle<S>) {
+        let (layer, handle) = reload::Layer::new(self.tracing_level_filter());
+        (layer, VerbosityReloadHandle { handle })
+    }
+}
+
+/// Changes the level of the layer from [`Verbosity::reloadable`]
+///
+/// Like the flags, changes saturate at [`VerbosityFilter::Off`] and [`VerbosityFilter::Trace`].
+#[derive(Clone, Debug)]
+pub struct VerbosityReloadHandle<S> {
+    handle: Handle<LevelFilter, S>,
+}
+
+impl<S> VerbosityReloadHandle<S> {
+    /// The current level, or `None` if the subscriber was dropped
+    pub fn get(&self) -> Option<VerbosityFilter> {
+        self.handle.clone_current().map(VerbosityFilter::from)
+    }
+
+    /// Replace the level
+    pub fn set(&self, filter: VerbosityFilter) -> Result<(), reload::Error> {
+        self.handle.reload(LevelFilter::from(filter))
+    }
+
+    /// Move one level towards [`VerbosityFilter::Trace`], like another `-v`
+    pub fn increase(&self) -> Result<(), reload::Error> {
+        self.offset(1)
+    }
+
+    /// Move one level towards [`VerbosityFilter::Off`], like another `-q`
+    pub fn decrease(&self) -> Result<(), reload::Error> {
+        self.offset(-1)
+    }
+
+    fn offset(&self, offset: i16) -> Result<(), reload::Error> {
+        self.handle.modify(|level| {
+            *level = VerbosityFilter::from(*level).with_offset(offset).into();
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing_subscriber::prelude::*;
+
+    use super::*;
+    use crate::tracing_subscriber::TestWriter;
+    use crate::ErrorLevel;
+
+    #[test]
+    fn reloadable() {
+        let buffer = TestWriter::default();
+        let (filter, handle) = Verbosity::<ErrorLevel>::new(1, 0).reloadable();
+        let subscriber = tracing_subscriber::registry().with(filter).with(
+            tracing_subscriber::fmt::layer()
+                .with_ansi(false)
+                .with_writer(buffer.clone()),
+        );
+        tracing::subscriber::with_default(subscriber, || {
+            assert_eq!(handle.get(), Some(VerbosityFilter::Warn));
+            tracing::info!("before increase");
+            handle.increase().unwrap();
+            tracing::info!("after increase");
+            handle.set(VerbosityFilter::Off).unwrap();
+            handle.decrease().unwrap();
+            assert_eq!(handle.get(), Some(VerbosityFilter::Off));
+            tracing::error!("after off");
+        });
+
+        let output = buffer.contents();
+        assert!(!output.contains("before increase"), "{output}");
+        assert!(output.contains("after increase"), "{output}");
+        assert!(!output.contains("after off"), "{output}");
+    }
+}
@@ -0,0 +1,205 @@
+use std::fmt;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+
+/// When [`LogArgs`][crate::LogArgs] starts a new log file
+///
+/// Parsed from `never`, `hourly`, `daily` or `size:<N>`, where `N` takes an optional `KB`, `MB` or
+/// `GB` suffix, in powers of 1024.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogRotation {
+    /// Append to a single file
+    #[default]
+    Never,
+    /// Start a new file every hour, suffixed with the date and hour
+    Hourly,
+    /// Start a new file every day, suffixed with the date
+    Daily,
+    /// Start a new file once it would grow past this many bytes, numbering the older ones
+    Size(u64),
+}
+
+impl FromStr for LogRotation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "never" => Ok(Self::Never),
+            "hourly" => Ok(Self::Hourly),
+            "daily" => Ok(Self::Daily),
+            _ => {
+                let size = s.strip_prefix("size:").ok_or_else(|| {
+                    format!("expected `never`, `hourly`, `daily` or `size:<N>`, got `{s}`")
+                })?;
+                parse_size(size).map(Self::Size)
+            }
+        }
+    }
+}
+
+impl fmt::Display for LogRotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Never => f.write_str("never"),
+            Self::Hourly => f.write_str("hourly"),
+            Self::Daily => f.write_str("daily"),
+            Self::Size(bytes) => write!(f, "size:{bytes}"),
+        }
+    }
+}
+
+fn parse_size(size: &str) -> Result<u64, String> {
+    let upper = size.to_ascii_uppercase();
+    let (digits, unit) = [("GB", 1 << 30), ("MB", 1 << 20), ("KB", 1 << 10), ("B", 1)]
+        .into_iter()
+        .find_map(|(suffix, unit)| upper.strip_suffix(suffix).map(|digits| (digits, unit)))
+        .unwrap_or((&upper, 1));
+    digits
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(unit))
+        .filter(|bytes| *bytes > 0)
+        .ok_or_else(|| format!("invalid size `{size}`, expected e.g. `10MB`"))
+}
+
+/// A log file moved to `<path>.1`, `<path>.2`, ... once it reaches a size
+#[derive(Debug)]
+pub(crate) struct SizeRotatingFile {
+    path: PathBuf,
+    max_bytes: u64,
+    max_files: Option<usize>,
+    state: Mutex<(File, u64)>,
+}
+
+impl SizeRotatingFile {
+    /// Append to `path`, keeping at most `max_files` files including the current one
+    pub(crate) fn open(path: &Path, max_bytes: u64, max_files: Option<usize>) -> io::Result<Self> {
+        let file = append(path)?;
+        let len = file.metadata()?.len();
+        Ok(Self {
+            path: path.to_owned(),
+            max_bytes,
+            max_files,
+            state: Mutex::new((file, len)),
+        })
+    }
+
+    fn numbered(&self, n: usize) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{n}"));
+        path.into()
+    }
+
+    /// Shift the older files up by one, dropping the oldest beyond `max_files`
+    fn rotate(&self) -> io::Result<()> {
+        let last = match self.max_files {
+            Some(max_files) => max_files.saturating_sub(1),
+            None => (1..).find(|n| !self.numbered(*n).exists()).unwrap_or(1),
+        };
+        // Also drop files left over from running with a higher limit
+        let mut stale = last.max(1);
+        while self.numbered(stale).exists() {
+            remove(&self.numbered(stale))?;
+            stale += 1;
+        }
+        if last == 0 {
+            return remove(&self.path);
+        }
+        for n in (1..last).rev() {
+            let from = self.numbered(n);
+            if from.exists() {
+                fs::rename(from, self.numbered(n + 1))?;
+            }
+        }
+        fs::rename(&self.path, self.numbered(1))
+    }
+}
+
+impl io::Write for &SizeRotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let (file, len) = &mut *state;
+        if *len > 0 && *len + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+            *file = append(&self.path)?;
+            *len = 0;
+        }
+        file.write_all(buf)?;
+        *len += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.0.flush()
+    }
+}
+
+fn append(path: &Path) -> io::Result<File> {
+    File::options().create(true).append(true).open(path)
+}
+
+fn remove(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write as _;
+
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!("never".parse(), Ok(LogRotation::Never));
+        assert_eq!("hourly".parse(), Ok(LogRotation::Hourly));
+        assert_eq!("daily".parse(), Ok(LogRotation::Daily));
+        assert_eq!("size:512".parse(), Ok(LogRotation::Size(512)));
+        assert_eq!("size:4kb".parse(), Ok(LogRotation::Size(4096)));
+        assert_eq!("size:10MB".parse(), Ok(LogRotation::Size(10 << 20)));
+        assert_eq!("size:1 GB".parse(), Ok(LogRotation::Size(1 << 30)));
+        assert!("size:0".parse::<LogRotation>().is_err());
+        assert!("size:MB".parse::<LogRotation>().is_err());
+        assert!("weekly".parse::<LogRotation>().is_err());
+        assert_eq!(LogRotation::Size(4096).to_string(), "size:4096");
+    }
+
+    #[test]
+    fn size_rotation() {
+        let dir =
+            std::env::temp_dir().join(format!("clap-verbosity-flag-rotate-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("app.log");
+        let read = |path: PathBuf| fs::read_to_string(path).unwrap_or_default();
+
+        let file = SizeRotatingFile::open(&path, 8, Some(3)).unwrap();
+        for line in ["one\n", "two\n", "three\n", "four\n", "five\n"] {
+            (&file).write_all(line.as_bytes()).unwrap();
+        }
+        assert_eq!(read(path.clone()), "five\n");
+        assert_eq!(read(file.numbered(1)), "four\n");
+        assert_eq!(read(file.numbered(2)), "three\n");
+        assert!(!file.numbered(3).exists());
+
+        let file = SizeRotatingFile::open(&path, 8, Some(2)).unwrap();
+        (&file).write_all(b"six\n").unwrap();
+        (&file).write_all(b"seven\n").unwrap();
+        assert_eq!(read(path.clone()), "seven\n");
+        assert_eq!(read(file.numbered(1)), "six\n");
+        assert!(!file.numbered(2).exists());
+
+        let file = SizeRotatingFile::open(&path, 8, Some(1)).unwrap();
+        (&file).write_all(b"eight\n").unwrap();
+        assert_eq!(read(path.clone()), "eight\n");
+        assert!(!file.numbered(1).exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
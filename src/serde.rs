@@ -0,0 +1,492 @@
+//! Helpers for [`serde`]
+//!
+//! [`VerbosityFilter`] and [`Verbosity`] are (de)serialized as the canonical, lowercase name of
+//! the level, e.g. `"debug"`.  Reading is lenient so hand-written configs don't fail on obvious
+//! spellings: case is ignored and the aliases of [`VerbosityFilterParser`] are accepted, e.g.
+//! `"WARNING"` or `"err"`.  For other representations, use the modules in here with
+//! `#[serde(with = "...")]`.
+//!
+//! [`VerbosityFilterParser`]: crate::VerbosityFilterParser
+
+use std::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, Unexpected, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{LogLevel, Verbosity, VerbosityFilter};
+
+/// Types the modules in here can (de)serialize
+pub trait AsFilter: Sized {
+    /// The level to write
+    fn as_filter(&self) -> VerbosityFilter;
+
+    /// Rebuild a value from the level that was read
+    fn from_filter(filter: VerbosityFilter) -> Self;
+}
+
+impl AsFilter for VerbosityFilter {
+    fn as_filter(&self) -> VerbosityFilter {
+        *self
+    }
+
+    fn from_filter(filter: VerbosityFilter) -> Self {
+        filter
+    }
+}
+
+/// Reads back the fewest `-v` or `-q`, see `From<VerbosityFilter> for Verbosity`
+impl<L: LogLevel> AsFilter for Verbosity<L> {
+    fn as_filter(&self) -> VerbosityFilter {
+        self.filter()
+    }
+
+    fn from_filter(filter: VerbosityFilter) -> Self {
+        filter.into()
+    }
+}
+
+impl Serialize for VerbosityFilter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for VerbosityFilter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FilterVisitor)
+    }
+}
+
+/// Writes only the resulting [`Verbosity::filter`]
+///
+/// The `-v`/`-q` counts, `--silent`, `--log-profile`, [`Verbosity::set_default`] and where the
+/// flags came from are not kept, so a deserialized value has the same filter but may differ in
+/// [`Verbosity::is_present`], [`Verbosity::verbose_source`] and [`Verbosity::quiet_source`].
+impl<L: LogLevel> Serialize for Verbosity<L> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.filter().serialize(serializer)
+    }
+}
+
+/// Reads back the fewest `-v` or `-q` reaching the level, see `From<VerbosityFilter> for Verbosity`
+///
+/// Nothing but the filter survives a round trip, see `Serialize for Verbosity`.
+impl<'de, L: LogLevel> Deserialize<'de> for Verbosity<L> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        VerbosityFilter::deserialize(deserializer).map(Self::from)
+    }
+}
+
+const NAMES: &[&str] = &["off", "error", "warn", "info", "debug", "trace"];
+
+struct FilterVisitor;
+
+impl Visitor<'_> for FilterVisitor {
+    type Value = VerbosityFilter;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a level name")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        crate::parser::parse_filter(value).ok_or_else(|| E::unknown_variant(value, NAMES))
+    }
+}
+
+/// (De)serialize as a number from `0` for off to `5` for trace
+///
+/// This is the scale of `From<VerbosityFilter> for u8`, for formats and systems that store levels
+/// numerically:
+/// ```rust
+/// # use clap_verbosity_flag::Verbosity;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Config {
+///     #[serde(with = "clap_verbosity_flag::serde::numeric")]
+///     verbosity: Verbosity,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"verbosity":4}"#).unwrap();
+/// assert_eq!(config.verbosity.verbose(), 3);
+/// ```
+pub mod numeric {
+    use super::{AsFilter, Deserialize, Deserializer, Serializer, Unexpected};
+    use crate::VerbosityFilter;
+
+    /// Write the level as a number
+    pub fn serialize<T: AsFilter, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(value.as_filter().into())
+    }
+
+    /// Read the level from a number
+    pub fn deserialize<'de, T: AsFilter, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let value = u8::deserialize(deserializer)?;
+        VerbosityFilter::try_from(value)
+            .map(T::from_filter)
+            .map_err(|_| {
+                serde::de::Error::invalid_value(
+                    Unexpected::Unsigned(value.into()),
+                    &"a level from 0 to 5",
+                )
+            })
+    }
+}
+
+/// (De)serialize the raw `--verbose` and `--quiet` counts of a [`Verbosity`]
+///
+/// Unlike the level, this keeps exactly what was passed, e.g. to re-emit the flags:
+/// ```rust
+/// # use clap_verbosity_flag::Verbosity;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Invocation {
+///     #[serde(with = "clap_verbosity_flag::serde::counts")]
+///     verbosity: Verbosity,
+/// }
+///
+/// let invocation = Invocation {
+///     verbosity: Verbosity::new(2, 0),
+/// };
+/// let json = serde_json::to_string(&invocation).unwrap();
+/// assert_eq!(json, r#"{"verbosity":{"verbose":2,"quiet":0}}"#);
+/// ```
+///
+/// Missing counts are read as `0`.
+pub mod counts {
+    use super::{Deserialize, Deserializer, Serialize, Serializer};
+    use crate::{LogLevel, Verbosity};
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(rename = "Verbosity")]
+    struct Counts {
+        #[serde(default)]
+        verbose: u8,
+        #[serde(default)]
+        quiet: u8,
+    }
+
+    /// Write the counts
+    pub fn serialize<L: LogLevel, S: Serializer>(
+        value: &Verbosity<L>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Counts {
+            verbose: value.verbose(),
+            quiet: value.quiet(),
+        }
+        .serialize(serializer)
+    }
+
+    /// Read the counts
+    pub fn deserialize<'de, L: LogLevel, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Verbosity<L>, D::Error> {
+        let counts = Counts::deserialize(deserializer)?;
+        Ok(Verbosity::new(counts.verbose, counts.quiet))
+    }
+}
+
+/// Types [`directive`] can (de)serialize
+pub trait AsDirectives: Sized {
+    /// The env-filter directives to write, e.g. `"myapp=debug,warn"`
+    fn to_directives(&self) -> String;
+
+    /// Rebuild a value from the directives that were read
+    fn from_directives(directives: &str) -> Result<Self, String>;
+}
+
+/// Only accepts levels without a target, the last one winning like with `RUST_LOG`
+impl<L: LogLevel> AsDirectives for Verbosity<L> {
+    fn to_directives(&self) -> String {
+        self.filter().as_str().to_owned()
+    }
+
+    fn from_directives(directives: &str) -> Result<Self, String> {
+        let Directives { global, targets } = Directives::parse(directives)?;
+        if let Some((target, _)) = targets.first() {
+            return Err(format!("unexpected directive for target `{target}`"));
+        }
+        Ok(global.map(Self::from).unwrap_or(Self::new(0, 0)))
+    }
+}
+
+/// Each scope becomes a target directive and a span directive, like `myapp=debug,[myapp]=debug`,
+/// followed by the level outside of the scopes
+///
+/// All targets and spans must share one level when reading, and each becomes a scope.  Span
+/// directives can't have a target or fields.  A level without a target is the level outside of
+/// the scopes, read back as the default when the `-v` counts alone don't lead to it.
+#[cfg(feature = "tracing-subscriber")]
+impl<L: LogLevel> AsDirectives for crate::tracing_subscriber::ScopedVerbosity<L> {
+    fn to_directives(&self) -> String {
+        let inside = self.verbosity().filter();
+        let mut directives: Vec<_> = self
+            .scopes()
+            .iter()
+            .flat_map(|scope| [format!("{scope}={inside}"), format!("[{scope}]={inside}")])
+            .collect();
+        directives.push(self.outside().to_string());
+        directives.join(",")
+    }
+
+    fn from_directives(directives: &str) -> Result<Self, String> {
+        let Directives { global, targets } = Directives::parse(directives)?;
+        let inside = match targets.first() {
+            Some((_, inside)) => {
+                if let Some((target, _)) = targets.iter().find(|(_, level)| level != inside) {
+                    return Err(format!(
+                        "level for target `{target}` differs from the others"
+                    ));
+                }
+                Some(*inside)
+            }
+            None => global,
+        };
+        let mut verbosity = inside.map(Verbosity::from).unwrap_or(Verbosity::new(0, 0));
+        if let (Some(global), Some(inside)) = (global.filter(|_| !targets.is_empty()), inside) {
+            // Outside of the scopes is the default, so raise from there to the inside level
+            if verbosity.outside_scopes() != global {
+                let offset = i16::from(u8::from(inside)) - i16::from(u8::from(global));
+                verbosity = Verbosity::new(0, 0).with_default(global) + offset;
+            }
+            let outside = verbosity.outside_scopes();
+            if global != outside || verbosity.filter() != inside {
+                return Err(format!(
+                    "level `{global}` outside of the scopes can't go with `{inside}` inside"
+                ));
+            }
+        }
+        let mut scopes = Vec::new();
+        for (target, _) in targets {
+            let scope = match target.strip_prefix('[') {
+                Some(span) => match span.strip_suffix(']') {
+                    Some(span) if !span.contains(['[', '{']) => span.to_owned(),
+                    _ => return Err(format!("unsupported span directive `{target}`")),
+                },
+                None if target.contains('[') => {
+                    return Err(format!("unsupported span directive `{target}`"))
+                }
+                None => target,
+            };
+            if !scopes.contains(&scope) {
+                scopes.push(scope);
+            }
+        }
+        Ok(Self::new(verbosity, scopes))
+    }
+}
+
+/// `RUST_LOG`-style directives
+struct Directives {
+    /// The last level without a target
+    global: Option<VerbosityFilter>,
+    targets: Vec<(String, VerbosityFilter)>,
+}
+
+impl Directives {
+    fn parse(directives: &str) -> Result<Self, String> {
+        let parse_level = |level: &str| {
+            crate::parser::parse_filter(level).ok_or_else(|| format!("unknown level `{level}`"))
+        };
+        let mut global = None;
+        let mut targets = Vec::new();
+        for directive in directives.split(',').map(str::trim) {
+            match directive.split_once('=') {
+                _ if directive.is_empty() => {}
+                Some((target, level)) => {
+                    targets.push((target.trim().to_owned(), parse_level(level)?));
+                }
+                None => global = Some(parse_level(directive)?),
+            }
+        }
+        Ok(Self { global, targets })
+    }
+}
+
+/// (De)serialize as env-filter directives, for systems that consume `RUST_LOG` syntax
+///
+/// A [`Verbosity`] is written as its level, e.g. `"debug"`.  With the `tracing-subscriber`
+/// feature, a [`ScopedVerbosity`] adds directives for each scope, e.g.
+/// `"myapp=debug,[myapp]=debug,warn"`.
+/// ```rust
+/// # use clap_verbosity_flag::Verbosity;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Config {
+///     #[serde(with = "clap_verbosity_flag::serde::directive")]
+///     verbosity: Verbosity,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"verbosity":"info"}"#).unwrap();
+/// assert_eq!(config.verbosity.verbose(), 2);
+/// ```
+///
+/// [`ScopedVerbosity`]: crate::tracing_subscriber::ScopedVerbosity
+pub mod directive {
+    use super::{AsDirectives, Deserialize, Deserializer, Serializer};
+
+    /// Write the directives as a string
+    pub fn serialize<T: AsDirectives, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_directives())
+    }
+
+    /// Read the directives from a string
+    pub fn deserialize<'de, T: AsDirectives, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let directives = String::deserialize(deserializer)?;
+        T::from_directives(&directives).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ErrorLevel, InfoLevel};
+
+    #[test]
+    fn name() {
+        let json = serde_json::to_string(&VerbosityFilter::Debug).unwrap();
+        assert_eq!(json, r#""debug""#);
+        let filter: VerbosityFilter = serde_json::from_str(&json).unwrap();
+        assert_eq!(filter, VerbosityFilter::Debug);
+        assert!(serde_json::from_str::<VerbosityFilter>(r#""loud""#).is_err());
+        for (json, filter) in [
+            (r#""Warn""#, VerbosityFilter::Warn),
+            (r#""WARNING""#, VerbosityFilter::Warn),
+            (r#""err""#, VerbosityFilter::Error),
+            (r#""information""#, VerbosityFilter::Info),
+        ] {
+            assert_eq!(
+                serde_json::from_str::<VerbosityFilter>(json).unwrap(),
+                filter
+            );
+        }
+
+        let verbosity = Verbosity::<ErrorLevel>::new(2, 0);
+        let json = serde_json::to_string(&verbosity).unwrap();
+        assert_eq!(json, r#""info""#);
+        let verbosity: Verbosity<InfoLevel> = serde_json::from_str(&json).unwrap();
+        assert_eq!((verbosity.verbose(), verbosity.quiet()), (0, 0));
+
+        // Only the filter round-trips
+        let verbosity = Verbosity::<InfoLevel>::new(1, 1);
+        assert!(verbosity.is_present());
+        let json = serde_json::to_string(&verbosity).unwrap();
+        let verbosity: Verbosity<InfoLevel> = serde_json::from_str(&json).unwrap();
+        assert_eq!(verbosity.filter(), VerbosityFilter::Info);
+        assert!(!verbosity.is_present());
+    }
+
+    #[test]
+    fn numeric() {
+        #[derive(Debug, serde::Serialize, serde::Deserialize)]
+        struct Config {
+            #[serde(with = "numeric")]
+            filter: VerbosityFilter,
+            #[serde(with = "numeric")]
+            verbosity: Verbosity,
+        }
+
+        let config = Config {
+            filter: VerbosityFilter::Warn,
+            verbosity: Verbosity::new(0, 1),
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"filter":2,"verbosity":0}"#);
+        let config: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(config.filter, VerbosityFilter::Warn);
+        assert_eq!(config.verbosity.filter(), VerbosityFilter::Off);
+
+        let err = serde_json::from_str::<Config>(r#"{"filter":6,"verbosity":0}"#).unwrap_err();
+        assert!(err.to_string().contains("a level from 0 to 5"));
+    }
+
+    #[test]
+    fn counts() {
+        #[derive(Debug, serde::Serialize, serde::Deserialize)]
+        struct Invocation {
+            #[serde(with = "counts")]
+            verbosity: Verbosity,
+        }
+
+        let invocation = Invocation {
+            verbosity: Verbosity::new(1, 1),
+        };
+        let json = serde_json::to_string(&invocation).unwrap();
+        assert_eq!(json, r#"{"verbosity":{"verbose":1,"quiet":1}}"#);
+        let invocation: Invocation = serde_json::from_str(&json).unwrap();
+        assert_eq!(invocation.verbosity.verbose(), 1);
+        assert_eq!(invocation.verbosity.quiet(), 1);
+
+        let invocation: Invocation = serde_json::from_str(r#"{"verbosity":{"quiet":2}}"#).unwrap();
+        assert_eq!(invocation.verbosity.quiet(), 2);
+        assert_eq!(invocation.verbosity.verbose(), 0);
+    }
+
+    #[test]
+    fn directive() {
+        #[derive(Debug, serde::Serialize, serde::Deserialize)]
+        struct Config {
+            #[serde(with = "directive")]
+            verbosity: Verbosity,
+        }
+
+        let config = Config {
+            verbosity: Verbosity::new(1, 0),
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"verbosity":"warn"}"#);
+
+        let parse = |json: &str| serde_json::from_str::<Config>(json).map(|c| c.verbosity.filter());
+        assert_eq!(
+            parse(r#"{"verbosity":"info,Debug"}"#).unwrap(),
+            VerbosityFilter::Debug
+        );
+        assert_eq!(
+            parse(r#"{"verbosity":""}"#).unwrap(),
+            VerbosityFilter::Error
+        );
+        assert!(parse(r#"{"verbosity":"myapp=debug,warn"}"#).is_err());
+        assert!(parse(r#"{"verbosity":"loud"}"#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "tracing-subscriber")]
+    fn scoped_directive() {
+        use crate::tracing_subscriber::ScopedVerbosity;
+
+        let scoped = ScopedVerbosity::<InfoLevel>::new(Verbosity::new(1, 0), vec!["myapp".into()]);
+        assert_eq!(scoped.to_directives(), "myapp=debug,[myapp]=debug,info");
+        let read = ScopedVerbosity::<InfoLevel>::from_directives(&scoped.to_directives()).unwrap();
+        assert_eq!(read.scopes(), ["myapp"]);
+        assert_eq!(read.verbosity().filter(), VerbosityFilter::Debug);
+        let scoped = ScopedVerbosity::<InfoLevel>::new(Verbosity::new(0, 1), vec![]);
+        assert_eq!(scoped.to_directives(), "warn");
+
+        let scoped =
+            ScopedVerbosity::<ErrorLevel>::from_directives("a=debug, b=debug, error").unwrap();
+        assert_eq!(scoped.scopes(), ["a", "b"]);
+        assert_eq!(scoped.verbosity().filter(), VerbosityFilter::Debug);
+        assert!(ScopedVerbosity::<ErrorLevel>::from_directives("a=debug,b=info").is_err());
+        assert!(ScopedVerbosity::<ErrorLevel>::from_directives("a=debug,trace").is_err());
+
+        // A runtime default shows up as the level outside of the scopes
+        let verbosity = Verbosity::new(1, 0).with_default(VerbosityFilter::Warn);
+        let scoped = ScopedVerbosity::<ErrorLevel>::new(verbosity, vec!["a".into()]);
+        assert_eq!(scoped.to_directives(), "a=info,[a]=info,warn");
+        let read = ScopedVerbosity::<ErrorLevel>::from_directives(&scoped.to_directives()).unwrap();
+        assert_eq!(read.verbosity().filter(), VerbosityFilter::Info);
+        assert_eq!(read.to_directives(), scoped.to_directives());
+
+        let scoped = ScopedVerbosity::<ErrorLevel>::from_directives("[request]=debug").unwrap();
+        assert_eq!(scoped.scopes(), ["request"]);
+        assert!(ScopedVerbosity::<ErrorLevel>::from_directives("a[b]=debug").is_err());
+        assert!(ScopedVerbosity::<ErrorLevel>::from_directives("[b{id=1}]=debug").is_err());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{ErrorLevel, LogLevel, Verbosity, VerbosityFilter};
+
+/// [`Verbosity`] plus `--debug` and `--trace` flags, to `#[command(flatten)]` into your CLI
+///
+/// `--debug` and `--trace` jump straight to their level, regardless of the default level or any
+/// `-v`.  When both are passed, `--trace` wins, and `--silent` wins over both.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ShortcutVerbosity<L: LogLevel = ErrorLevel> {
+    verbosity: Verbosity<L>,
+    debug: bool,
+    trace: bool,
+}
+
+impl<L: LogLevel> ShortcutVerbosity<L> {
+    /// The [`clap::ArgGroup`] containing `--debug` and `--trace`
+    ///
+    /// `-v` and `-q` stay in [`Verbosity::GROUP_ID`].
+    pub const GROUP_ID: &'static str = "clap_verbosity_shortcuts";
+
+    /// Create a new instance by explicitly setting the values
+    pub fn new(verbosity: Verbosity<L>, debug: bool, trace: bool) -> Self {
+        Self {
+            verbosity,
+            debug,
+            trace,
+        }
+    }
+
+    /// The `-v` and `-q` counts
+    pub fn verbosity(&self) -> &Verbosity<L> {
+        &self.verbosity
+    }
+
+    /// Whether any verbosity flags are present on the command line.
+    pub fn is_present(&self) -> bool {
+        self.debug || self.trace || self.verbosity.is_present()
+    }
+
+    /// Gets the filter that should be applied to the logger.
+    pub fn filter(&self) -> VerbosityFilter {
+        if self.verbosity.silent {
+            self.verbosity.filter()
+        } else if self.trace {
+            crate::clamp::<L>(VerbosityFilter::Trace)
+        } else if self.debug {
+            crate::clamp::<L>(VerbosityFilter::Debug)
+        } else {
+            self.verbosity.filter()
+        }
+    }
+}
+
+filter_accessors!(ShortcutVerbosity);
+
+impl<L: LogLevel> clap::FromArgMatches for ShortcutVerbosity<L> {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut verbosity = Self::new(Verbosity::new(0, 0), false, false);
+        verbosity.update_from_arg_matches(matches)?;
+        Ok(verbosity)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        self.verbosity.update_from_arg_matches(matches)?;
+        self.debug = crate::get_flag::<L>(matches, &crate::prefixed::<L>(DEBUG_ID));
+        self.trace = crate::get_flag::<L>(matches, &crate::prefixed::<L>(TRACE_ID));
+        Ok(())
+    }
+}
+
+impl<L: LogLevel> clap::Args for ShortcutVerbosity<L> {
+    fn group_id() -> Option<clap::Id> {
+        Some(clap::Id::from(crate::prefixed::<L>(Self::GROUP_ID)))
+    }
+
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        <Verbosity<L> as clap::Args>::augment_args(cmd)
+            .group(crate::flag_group::<L>(
+                Self::GROUP_ID,
+                &[DEBUG_ID, TRACE_ID],
+            ))
+            .arg(shortcut_arg::<L>(DEBUG_ID).help("Show debug messages"))
+            .arg(shortcut_arg::<L>(TRACE_ID).help("Show all messages, including traces"))
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+const DEBUG_ID: &str = "debug";
+const TRACE_ID: &str = "trace";
+
+fn shortcut_arg<L: LogLevel>(id: &'static str) -> clap::Arg {
+    let arg = crate::flag_arg::<L>(id)
+        .long(crate::prefixed::<L>(id))
+        .action(clap::ArgAction::SetTrue);
+    if L::allow_verbose_and_quiet() {
+        arg
+    } else {
+        arg.conflicts_with(crate::prefixed::<L>(crate::QUIET_ID))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::{CommandFactory, Parser};
+
+    use super::*;
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(flatten)]
+        verbosity: ShortcutVerbosity,
+    }
+
+    #[test]
+    fn verify_app() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn parse() {
+        let cli = Cli::try_parse_from(["cmd"]).unwrap();
+        assert_eq!(cli.verbosity.filter(), VerbosityFilter::Error);
+        assert!(!cli.verbosity.is_present());
+
+        let cli = Cli::try_parse_from(["cmd", "-v"]).unwrap();
+        assert_eq!(cli.verbosity.filter(), VerbosityFilter::Warn);
+
+        let cli = Cli::try_parse_from(["cmd", "--debug"]).unwrap();
+        assert_eq!(cli.verbosity.filter(), VerbosityFilter::Debug);
+        assert!(cli.verbosity.is_present());
+
+        let cli = Cli::try_parse_from(["cmd", "--debug", "--trace"]).unwrap();
+        assert_eq!(cli.verbosity.filter(), VerbosityFilter::Trace);
+
+        let cli = Cli::try_parse_from(["cmd", "-vvvvv", "--debug"]).unwrap();
+        assert_eq!(cli.verbosity.filter(), VerbosityFilter::Debug);
+
+        assert!(Cli::try_parse_from(["cmd", "-q", "--trace"]).is_err());
+    }
+
+    #[test]
+    fn silent() {
+        #[derive(Debug)]
+        struct Silent;
+
+        impl LogLevel for Silent {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Error
+            }
+
+            fn silent_long() -> Option<&'static str> {
+                Some("silent")
+            }
+        }
+
+        #[derive(Debug, Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbosity: ShortcutVerbosity<Silent>,
+        }
+
+        Cli::command().debug_assert();
+        let cli = Cli::try_parse_from(["cmd", "--silent", "--debug"]).unwrap();
+        assert_eq!(cli.verbosity.filter(), VerbosityFilter::Off);
+        assert!(cli.verbosity.is_silent());
+        let cli = Cli::try_parse_from(["cmd", "--trace", "--silent"]).unwrap();
+        assert_eq!(cli.verbosity.filter(), VerbosityFilter::Off);
+    }
+}
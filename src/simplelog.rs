@@ -0,0 +1,78 @@
+//! Set up [`simplelog`] from the flags
+
+// These re-exports of the simplelog crate make it easy to use this crate without having to depend
+// on the simplelog crate directly.  `simplelog::LevelFilter` is `log::LevelFilter`, so the
+// conversions from the `log` module apply.
+pub use simplelog::{Config, LevelFilter};
+
+use simplelog::{ColorChoice, SimpleLogger, TermLogger, TerminalMode};
+
+use crate::{LogLevel, Verbosity};
+
+impl<L: LogLevel> Verbosity<L> {
+    /// A [`TermLogger`] writing to stderr at the flags' level, e.g. for a `CombinedLogger`
+    pub fn term_logger(&self, config: Config) -> Box<TermLogger> {
+        TermLogger::new(
+            self.log_level_filter(),
+            config,
+            TerminalMode::Stderr,
+            ColorChoice::Auto,
+        )
+    }
+
+    /// Install [`Verbosity::term_logger`] as the global logger
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// # use clap_verbosity_flag::simplelog::Config;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose.init_term_logger(Config::default()).unwrap();
+    /// ```
+    pub fn init_term_logger(&self, config: Config) -> Result<(), log::SetLoggerError> {
+        TermLogger::init(
+            self.log_level_filter(),
+            config,
+            TerminalMode::Stderr,
+            ColorChoice::Auto,
+        )
+    }
+
+    /// A [`SimpleLogger`] at the flags' level, writing uncolored to stdout and stderr
+    pub fn simple_logger(&self, config: Config) -> Box<SimpleLogger> {
+        SimpleLogger::new(self.log_level_filter(), config)
+    }
+
+    /// Install [`Verbosity::simple_logger`] as the global logger
+    pub fn init_simple_logger(&self, config: Config) -> Result<(), log::SetLoggerError> {
+        SimpleLogger::init(self.log_level_filter(), config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use simplelog::SharedLogger as _;
+
+    use super::*;
+    use crate::InfoLevel;
+
+    #[test]
+    fn loggers() {
+        let verbosity = Verbosity::<InfoLevel>::new(1, 0);
+        assert_eq!(
+            verbosity.term_logger(Config::default()).level(),
+            LevelFilter::Debug
+        );
+        assert_eq!(
+            verbosity.simple_logger(Config::default()).level(),
+            LevelFilter::Debug
+        );
+    }
+}
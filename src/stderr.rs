@@ -0,0 +1,223 @@
+//! A minimal [`log::Log`] writing `level: message` lines to stderr
+//!
+//! This gets small CLIs working `-v` and `-q` without a separate logging crate.
+
+use std::io::{self, Write as _};
+
+use crate::{ColorChoice, LevelStyles, LogLevel, Verbosity, VerbosityFilter};
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Install a [`StderrLogger`] at the flags' level as the global logger
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose.init_stderr_logger();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If a global logger was already installed, see [`Verbosity::try_init_stderr_logger`].
+    pub fn init_stderr_logger(&self) {
+        self.try_init_stderr_logger().expect(
+            "Verbosity::init_stderr_logger should not be called after the logger is initialized",
+        );
+    }
+
+    /// Install a [`StderrLogger`] at the flags' level, failing if a global logger already is
+    pub fn try_init_stderr_logger(&self) -> Result<(), log::SetLoggerError> {
+        self.try_init_stderr_logger_with_color(ColorChoice::Auto)
+    }
+
+    /// Install a [`StderrLogger`] at the flags' level, colored according to `color`
+    ///
+    /// e.g. with a [`ColorArg`][crate::ColorArg] flattened next to the flags:
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::{ColorArg, Verbosity};
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// #     #[command(flatten)]
+    /// #     color: ColorArg,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose
+    ///     .try_init_stderr_logger_with_color(cli.color.choice())
+    ///     .unwrap();
+    /// ```
+    pub fn try_init_stderr_logger_with_color(
+        &self,
+        color: ColorChoice,
+    ) -> Result<(), log::SetLoggerError> {
+        self.install_stderr_logger(StderrLogger::new::<L>(self.filter()).with_color(color))
+    }
+
+    /// Install a [`StderrLogger`] at the flags' level, with warnings and errors on stderr and
+    /// everything else on stdout, failing if a global logger already is
+    ///
+    /// Labels are colored according to `color`, see [`StderrLogger::with_split`].
+    pub fn try_init_stderr_logger_split(
+        &self,
+        color: ColorChoice,
+    ) -> Result<(), log::SetLoggerError> {
+        let logger = StderrLogger::new::<L>(self.filter())
+            .with_color(color)
+            .with_split(true);
+        self.install_stderr_logger(logger)
+    }
+
+    fn install_stderr_logger(&self, logger: StderrLogger) -> Result<(), log::SetLoggerError> {
+        log::set_boxed_logger(Box::new(logger))?;
+        self.apply();
+        Ok(())
+    }
+}
+
+/// Writes each record at or below a level to stderr as `level: message`
+///
+/// Labels are colored with [`LogLevel::level_styles`] following [`ColorChoice::Auto`], see
+/// [`StderrLogger::with_color`].  Below warnings, records can go to stdout instead, see
+/// [`StderrLogger::with_split`].
+#[derive(Debug)]
+pub struct StderrLogger {
+    filter: VerbosityFilter,
+    styles: LevelStyles,
+    color: bool,
+    stdout_color: bool,
+    split: bool,
+}
+
+impl StderrLogger {
+    /// Write records let through by `filter`, styled with `L`'s [`LogLevel::level_styles`]
+    pub fn new<L: LogLevel>(filter: VerbosityFilter) -> Self {
+        Self {
+            filter,
+            styles: L::level_styles(),
+            color: false,
+            stdout_color: false,
+            split: false,
+        }
+        .with_color(ColorChoice::Auto)
+    }
+
+    /// Color the labels according to `choice`
+    pub fn with_color(mut self, choice: ColorChoice) -> Self {
+        self.color = choice.should_color(&io::stderr());
+        self.stdout_color = choice.should_color(&io::stdout());
+        self
+    }
+
+    /// Write info, debug and trace records to stdout, keeping warnings and errors on stderr
+    ///
+    /// This keeps diagnostics out of the output consumed by pipelines.
+    pub fn with_split(mut self, split: bool) -> Self {
+        self.split = split;
+        self
+    }
+
+    /// Whether `level` goes to stdout rather than stderr
+    fn to_stdout(&self, level: log::Level) -> bool {
+        self.split && level > log::Level::Warn
+    }
+
+    fn format(&self, record: &log::Record<'_>) -> String {
+        let level = VerbosityFilter::from(record.level());
+        let color = if self.to_stdout(record.level()) {
+            self.stdout_color
+        } else {
+            self.color
+        };
+        if color {
+            let style = self.styles.get(level);
+            format!("{style}{level}{style:#}: {}\n", record.args())
+        } else {
+            format!("{level}: {}\n", record.args())
+        }
+    }
+}
+
+impl log::Log for StderrLogger {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        self.filter.is_enabled(metadata.level())
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if self.enabled(record.metadata()) {
+            // Write the line at once so records from different threads don't interleave
+            let line = self.format(record);
+            let _ = if self.to_stdout(record.level()) {
+                io::stdout().lock().write_all(line.as_bytes())
+            } else {
+                io::stderr().lock().write_all(line.as_bytes())
+            };
+        }
+    }
+
+    fn flush(&self) {
+        let _ = io::stdout().flush();
+        let _ = io::stderr().flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use log::Log as _;
+
+    use super::*;
+    use crate::ErrorLevel;
+
+    fn logger(color: ColorChoice) -> StderrLogger {
+        StderrLogger::new::<ErrorLevel>(VerbosityFilter::Warn).with_color(color)
+    }
+
+    #[test]
+    fn enabled() {
+        let logger = logger(ColorChoice::Never);
+        let metadata = |level| log::Metadata::builder().level(level).build();
+        assert!(logger.enabled(&metadata(log::Level::Error)));
+        assert!(logger.enabled(&metadata(log::Level::Warn)));
+        assert!(!logger.enabled(&metadata(log::Level::Info)));
+    }
+
+    #[test]
+    fn format() {
+        let record = |logger: &StderrLogger| {
+            logger.format(
+                &log::Record::builder()
+                    .level(log::Level::Warn)
+                    .args(format_args!("smoking"))
+                    .build(),
+            )
+        };
+        assert_eq!(record(&logger(ColorChoice::Never)), "warn: smoking\n");
+        let style = LevelStyles::styled().get(VerbosityFilter::Warn);
+        assert_eq!(
+            record(&logger(ColorChoice::Always)),
+            format!("{style}warn{style:#}: smoking\n")
+        );
+    }
+
+    #[test]
+    fn split() {
+        let logger = logger(ColorChoice::Never);
+        assert!(!logger.to_stdout(log::Level::Info));
+
+        let logger = logger.with_split(true);
+        assert!(!logger.to_stdout(log::Level::Error));
+        assert!(!logger.to_stdout(log::Level::Warn));
+        assert!(logger.to_stdout(log::Level::Info));
+        assert!(logger.to_stdout(log::Level::Trace));
+    }
+}
@@ -0,0 +1,76 @@
+//! Set up [`stderrlog`] from the flags
+
+use stderrlog::StdErrLog;
+
+use crate::{LogLevel, Verbosity};
+
+impl<L: LogLevel> Verbosity<L> {
+    /// The value for [`StdErrLog::verbosity`]
+    ///
+    /// stderrlog counts from `0` for errors only up to `4` for trace, so
+    /// [`VerbosityFilter::Off`][crate::VerbosityFilter::Off] is reported through
+    /// [`Verbosity::stderrlog_quiet`] instead.
+    pub fn stderrlog_verbosity(&self) -> usize {
+        (self.filter() as usize).saturating_sub(1)
+    }
+
+    /// The value for [`StdErrLog::quiet`]
+    pub fn stderrlog_quiet(&self) -> bool {
+        self.is_silent()
+    }
+
+    /// A [`StdErrLog`] at the flags' level, to customize before calling [`StdErrLog::init`]
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose
+    ///     .stderrlog()
+    ///     .module(module_path!())
+    ///     .init()
+    ///     .unwrap();
+    /// ```
+    pub fn stderrlog(&self) -> StdErrLog {
+        let mut logger = stderrlog::new();
+        logger
+            .verbosity(self.stderrlog_verbosity())
+            .quiet(self.stderrlog_quiet());
+        logger
+    }
+
+    /// Install [`Verbosity::stderrlog`] as the global logger
+    pub fn init_stderrlog(&self) -> Result<(), log::SetLoggerError> {
+        self.stderrlog().init()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ErrorLevel, VerbosityFilter};
+
+    #[test]
+    fn stderrlog_levels() {
+        for filter in VerbosityFilter::iter() {
+            let logger = Verbosity::<ErrorLevel>::from(filter).stderrlog();
+            for level in [log::Level::Error, log::Level::Info, log::Level::Trace] {
+                assert_eq!(
+                    log::Log::enabled(
+                        &logger,
+                        &log::Metadata::builder().level(level).target("").build()
+                    ),
+                    filter.is_enabled(level),
+                    "{filter:?} {level:?}"
+                );
+            }
+        }
+    }
+}
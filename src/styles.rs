@@ -0,0 +1,146 @@
+use anstyle::{AnsiColor, Color, Style};
+
+use crate::VerbosityFilter;
+
+/// Terminal styling for each level's label
+///
+/// Supply these through [`LogLevel::level_styles`][crate::LogLevel::level_styles].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LevelStyles {
+    error: Style,
+    warn: Style,
+    info: Style,
+    debug: Style,
+    trace: Style,
+}
+
+impl LevelStyles {
+    /// No terminal styling
+    pub const fn plain() -> Self {
+        Self {
+            error: Style::new(),
+            warn: Style::new(),
+            info: Style::new(),
+            debug: Style::new(),
+            trace: Style::new(),
+        }
+    }
+
+    /// Default terminal styling
+    pub const fn styled() -> Self {
+        Self {
+            error: Style::new()
+                .fg_color(Some(Color::Ansi(AnsiColor::Red)))
+                .bold(),
+            warn: Style::new().fg_color(Some(Color::Ansi(AnsiColor::Yellow))),
+            info: Style::new().fg_color(Some(Color::Ansi(AnsiColor::Green))),
+            debug: Style::new().fg_color(Some(Color::Ansi(AnsiColor::Blue))),
+            trace: Style::new().fg_color(Some(Color::Ansi(AnsiColor::Cyan))),
+        }
+    }
+
+    /// Error records
+    #[inline]
+    pub const fn error(mut self, style: Style) -> Self {
+        self.error = style;
+        self
+    }
+
+    /// Warning records
+    #[inline]
+    pub const fn warn(mut self, style: Style) -> Self {
+        self.warn = style;
+        self
+    }
+
+    /// Info records
+    #[inline]
+    pub const fn info(mut self, style: Style) -> Self {
+        self.info = style;
+        self
+    }
+
+    /// Debug records
+    #[inline]
+    pub const fn debug(mut self, style: Style) -> Self {
+        self.debug = style;
+        self
+    }
+
+    /// Trace records
+    #[inline]
+    pub const fn trace(mut self, style: Style) -> Self {
+        self.trace = style;
+        self
+    }
+}
+
+impl LevelStyles {
+    /// Error records
+    #[inline(always)]
+    pub const fn get_error(&self) -> &Style {
+        &self.error
+    }
+
+    /// Warning records
+    #[inline(always)]
+    pub const fn get_warn(&self) -> &Style {
+        &self.warn
+    }
+
+    /// Info records
+    #[inline(always)]
+    pub const fn get_info(&self) -> &Style {
+        &self.info
+    }
+
+    /// Debug records
+    #[inline(always)]
+    pub const fn get_debug(&self) -> &Style {
+        &self.debug
+    }
+
+    /// Trace records
+    #[inline(always)]
+    pub const fn get_trace(&self) -> &Style {
+        &self.trace
+    }
+
+    /// The style for records at `level`
+    ///
+    /// [`VerbosityFilter::Off`] has no records and is unstyled.
+    pub fn get(&self, level: VerbosityFilter) -> Style {
+        match level {
+            VerbosityFilter::Off => Style::new(),
+            VerbosityFilter::Error => self.error,
+            VerbosityFilter::Warn => self.warn,
+            VerbosityFilter::Info => self.info,
+            VerbosityFilter::Debug => self.debug,
+            VerbosityFilter::Trace => self.trace,
+        }
+    }
+}
+
+impl Default for LevelStyles {
+    fn default() -> Self {
+        Self::styled()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get() {
+        let branded = Style::new().fg_color(Some(Color::Ansi(AnsiColor::Magenta)));
+        let styles = LevelStyles::plain().warn(branded);
+        assert_eq!(styles.get(VerbosityFilter::Warn), branded);
+        assert_eq!(styles.get(VerbosityFilter::Error), Style::new());
+        assert_eq!(styles.get(VerbosityFilter::Off), Style::new());
+        assert_eq!(
+            LevelStyles::styled().get(VerbosityFilter::Info),
+            *LevelStyles::default().get_info()
+        );
+    }
+}
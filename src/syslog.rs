@@ -0,0 +1,238 @@
+//! [Syslog](https://www.rfc-editor.org/rfc/rfc5424#section-6.2.1) severity support
+
+use std::fmt;
+#[cfg(all(unix, feature = "tracing-subscriber"))]
+use std::io;
+#[cfg(all(unix, feature = "tracing-subscriber"))]
+use std::os::unix::net::UnixDatagram;
+#[cfg(all(unix, feature = "tracing-subscriber"))]
+use std::sync::Arc;
+
+use crate::VerbosityFilter;
+
+/// An RFC 5424 severity, from the most to the least severe
+///
+/// Syslog has more severities at the top of the scale than log levels, and none below debug:
+/// - [`VerbosityFilter::Error`] maps to [`Severity::Error`], and the more severe
+///   [`Severity::Emergency`], [`Severity::Alert`] and [`Severity::Critical`] all map back to it
+/// - [`VerbosityFilter::Info`] maps to [`Severity::Informational`], and [`Severity::Notice`] maps
+///   back to it
+/// - [`VerbosityFilter::Trace`] maps to [`Severity::Debug`]
+/// - [`VerbosityFilter::Off`] maps to [`Severity::Emergency`], so a severity mask built from it
+///   only lets through messages about the system being unusable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Emergency = 0,
+    Alert = 1,
+    Critical = 2,
+    Error = 3,
+    Warning = 4,
+    Notice = 5,
+    Informational = 6,
+    Debug = 7,
+}
+
+impl Severity {
+    /// The numerical code, used in the `PRI` part of a message
+    pub fn code(&self) -> u8 {
+        *self as u8
+    }
+
+    /// The keyword from RFC 5424, e.g. for `syslog.conf` selectors
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Emergency => "emerg",
+            Self::Alert => "alert",
+            Self::Critical => "crit",
+            Self::Error => "err",
+            Self::Warning => "warning",
+            Self::Notice => "notice",
+            Self::Informational => "info",
+            Self::Debug => "debug",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<VerbosityFilter> for Severity {
+    fn from(filter: VerbosityFilter) -> Self {
+        match filter {
+            VerbosityFilter::Off => Self::Emergency,
+            VerbosityFilter::Error => Self::Error,
+            VerbosityFilter::Warn => Self::Warning,
+            VerbosityFilter::Info => Self::Informational,
+            VerbosityFilter::Debug | VerbosityFilter::Trace => Self::Debug,
+        }
+    }
+}
+
+impl From<Severity> for VerbosityFilter {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Emergency | Severity::Alert | Severity::Critical | Severity::Error => {
+                Self::Error
+            }
+            Severity::Warning => Self::Warn,
+            Severity::Notice | Severity::Informational => Self::Info,
+            Severity::Debug => Self::Debug,
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+impl From<log::Level> for Severity {
+    fn from(level: log::Level) -> Self {
+        VerbosityFilter::from(Some(level)).into()
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl From<tracing_core::Level> for Severity {
+    fn from(level: tracing_core::Level) -> Self {
+        VerbosityFilter::from(Some(level)).into()
+    }
+}
+
+/// A [`MakeWriter`][tracing_subscriber::fmt::MakeWriter] sending each event to the local syslog
+/// daemon
+///
+/// Each formatted event becomes one `<PRI>ident[pid]: line` datagram, with the user facility and
+/// the event's [`Severity`].
+#[cfg(all(unix, feature = "tracing-subscriber"))]
+#[derive(Debug, Clone)]
+pub struct SyslogWriter {
+    socket: Arc<UnixDatagram>,
+    ident: Arc<str>,
+}
+
+#[cfg(all(unix, feature = "tracing-subscriber"))]
+impl SyslogWriter {
+    /// Send to `/dev/log`, or `/var/run/syslog` on macOS, tagging messages with `ident`
+    pub fn connect(ident: &str) -> io::Result<Self> {
+        let socket = UnixDatagram::unbound()?;
+        socket
+            .connect("/dev/log")
+            .or_else(|_| socket.connect("/var/run/syslog"))?;
+        Ok(Self::new(socket, ident))
+    }
+
+    /// Send to an already connected `socket`, tagging messages with `ident`
+    pub fn new(socket: UnixDatagram, ident: &str) -> Self {
+        Self {
+            socket: Arc::new(socket),
+            ident: ident.into(),
+        }
+    }
+
+    fn message(&self, severity: Severity) -> SyslogMessage<'_> {
+        SyslogMessage {
+            writer: self,
+            severity,
+        }
+    }
+}
+
+#[cfg(all(unix, feature = "tracing-subscriber"))]
+impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for SyslogWriter {
+    type Writer = SyslogMessage<'a>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.message(Severity::Informational)
+    }
+
+    fn make_writer_for(&'a self, meta: &tracing_core::Metadata<'_>) -> Self::Writer {
+        self.message((*meta.level()).into())
+    }
+}
+
+/// The [`io::Write`] for a single event, see [`SyslogWriter`]
+#[cfg(all(unix, feature = "tracing-subscriber"))]
+#[derive(Debug)]
+pub struct SyslogMessage<'a> {
+    writer: &'a SyslogWriter,
+    severity: Severity,
+}
+
+#[cfg(all(unix, feature = "tracing-subscriber"))]
+impl io::Write for SyslogMessage<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let priority = USER_FACILITY * 8 + self.severity.code();
+        let message = format!(
+            "<{priority}>{}[{}]: {}",
+            self.writer.ident,
+            std::process::id(),
+            String::from_utf8_lossy(buf).trim_end()
+        );
+        self.writer.socket.send(message.as_bytes())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(all(unix, feature = "tracing-subscriber"))]
+const USER_FACILITY: u8 = 1;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_filter() {
+        assert_eq!(Severity::from(VerbosityFilter::Off), Severity::Emergency);
+        assert_eq!(Severity::from(VerbosityFilter::Error), Severity::Error);
+        assert_eq!(Severity::from(VerbosityFilter::Warn), Severity::Warning);
+        assert_eq!(
+            Severity::from(VerbosityFilter::Info),
+            Severity::Informational
+        );
+        assert_eq!(Severity::from(VerbosityFilter::Debug), Severity::Debug);
+        assert_eq!(Severity::from(VerbosityFilter::Trace), Severity::Debug);
+        assert_eq!(Severity::Warning.code(), 4);
+        assert_eq!(Severity::Critical.to_string(), "crit");
+    }
+
+    #[test]
+    fn into_filter() {
+        assert_eq!(
+            VerbosityFilter::from(Severity::Alert),
+            VerbosityFilter::Error
+        );
+        assert_eq!(
+            VerbosityFilter::from(Severity::Notice),
+            VerbosityFilter::Info
+        );
+        for filter in VerbosityFilter::iter().skip(1).take(4) {
+            assert_eq!(VerbosityFilter::from(Severity::from(filter)), filter);
+        }
+    }
+
+    #[test]
+    #[cfg(all(unix, feature = "tracing-subscriber"))]
+    fn syslog_writer() {
+        use tracing_subscriber::prelude::*;
+
+        let (sender, receiver) = UnixDatagram::pair().unwrap();
+        let subscriber = tracing_subscriber::registry().with(
+            tracing_subscriber::fmt::layer()
+                .with_ansi(false)
+                .without_time()
+                .with_target(false)
+                .with_writer(SyslogWriter::new(sender, "app")),
+        );
+        tracing::subscriber::with_default(subscriber, || tracing::warn!("disk full"));
+
+        let mut buf = [0; 256];
+        let len = receiver.recv(&mut buf).unwrap();
+        let message = std::str::from_utf8(&buf[..len]).unwrap();
+        let prefix = format!("<12>app[{}]: ", std::process::id());
+        assert_eq!(message, format!("{prefix} WARN disk full"));
+    }
+}
@@ -0,0 +1,196 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use clap::parser::ValueSource;
+
+/// How to prefix log lines with the time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Timestamps {
+    /// No timestamps
+    #[default]
+    None,
+    /// UTC to the second, like `2024-05-01T12:00:00Z`
+    Seconds,
+    /// UTC to the millisecond, like `2024-05-01T12:00:00.123Z`
+    Millis,
+    /// UTC to the microsecond, like `2024-05-01T12:00:00.123456Z`
+    Rfc3339,
+    /// Local time to the millisecond with its offset, like `2024-05-01T14:00:00.123+02:00`
+    Local,
+}
+
+impl Timestamps {
+    /// Whether lines get a timestamp at all
+    pub fn is_enabled(&self) -> bool {
+        *self != Self::None
+    }
+
+    /// The current time in this format, `None` for [`Timestamps::None`]
+    pub fn now(&self) -> Option<String> {
+        self.format(Utc::now())
+    }
+
+    fn format(&self, time: DateTime<Utc>) -> Option<String> {
+        match self {
+            Self::None => None,
+            Self::Seconds => Some(time.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            Self::Millis => Some(time.to_rfc3339_opts(SecondsFormat::Millis, true)),
+            Self::Rfc3339 => Some(time.to_rfc3339_opts(SecondsFormat::Micros, true)),
+            Self::Local => Some(
+                time.with_timezone(&chrono::Local)
+                    .to_rfc3339_opts(SecondsFormat::Millis, false),
+            ),
+        }
+    }
+}
+
+/// `--timestamps` option to `#[command(flatten)]` into your CLI, next to
+/// [`Verbosity`][crate::Verbosity]
+///
+/// `--timestamps` alone selects [`Timestamps::Rfc3339`], `--timestamps=<FORMAT>` any other.
+///
+/// ```rust,no_run
+/// # use clap::Parser;
+/// # use clap_verbosity_flag::{TimestampsArg, Verbosity};
+/// #
+/// # /// Le CLI
+/// # #[derive(Debug, Parser)]
+/// # struct Cli {
+/// #     #[command(flatten)]
+/// #     verbose: Verbosity,
+/// #     #[command(flatten)]
+/// #     timestamps: TimestampsArg,
+/// # }
+/// let cli = Cli::parse();
+/// if let Some(now) = cli.timestamps.timestamps().now() {
+///     eprintln!("{now} starting");
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimestampsArg {
+    timestamps: Timestamps,
+    source: Option<ValueSource>,
+}
+
+impl TimestampsArg {
+    /// Create a new instance by explicitly setting the format
+    pub fn new(timestamps: Timestamps) -> Self {
+        Self {
+            timestamps,
+            source: None,
+        }
+    }
+
+    /// The requested format, [`Timestamps::None`] when `--timestamps` is absent
+    pub fn timestamps(&self) -> Timestamps {
+        self.timestamps
+    }
+
+    /// Whether `--timestamps` was passed on the command line
+    pub fn is_present(&self) -> bool {
+        self.source == Some(ValueSource::CommandLine)
+    }
+}
+
+impl From<Timestamps> for TimestampsArg {
+    fn from(timestamps: Timestamps) -> Self {
+        Self::new(timestamps)
+    }
+}
+
+impl clap::FromArgMatches for TimestampsArg {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut timestamps = Self::default();
+        timestamps.update_from_arg_matches(matches)?;
+        Ok(timestamps)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        if let Some(timestamps) = matches.get_one::<Timestamps>(TIMESTAMPS_ID) {
+            self.timestamps = *timestamps;
+            self.source = matches.value_source(TIMESTAMPS_ID);
+        }
+        Ok(())
+    }
+}
+
+impl clap::Args for TimestampsArg {
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        cmd.arg(timestamps_arg(TIMESTAMPS_ID.to_owned()).global(true))
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+/// An option selecting [`Timestamps`], with the value optional
+pub(crate) fn timestamps_arg(id: String) -> clap::Arg {
+    clap::Arg::new(id.clone())
+        .long(id)
+        .value_name("FORMAT")
+        .value_parser(clap::value_parser!(Timestamps))
+        .num_args(0..=1)
+        .require_equals(true)
+        .default_missing_value("rfc3339")
+        .help("Prefix log lines with the time")
+}
+
+const TIMESTAMPS_ID: &str = "timestamps";
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone as _, Timelike as _};
+    use clap::{CommandFactory as _, Parser};
+
+    use super::*;
+
+    #[derive(Debug, Parser)]
+    struct Cli {
+        #[command(flatten)]
+        timestamps: TimestampsArg,
+        #[command(flatten)]
+        verbose: crate::Verbosity,
+    }
+
+    #[test]
+    fn verify_app() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn parse() {
+        let cli = Cli::try_parse_from(["cmd"]).unwrap();
+        assert_eq!(cli.timestamps.timestamps(), Timestamps::None);
+        assert!(!cli.timestamps.is_present());
+
+        let cli = Cli::try_parse_from(["cmd", "--timestamps", "-v"]).unwrap();
+        assert_eq!(cli.timestamps.timestamps(), Timestamps::Rfc3339);
+        assert!(cli.timestamps.is_present());
+
+        let cli = Cli::try_parse_from(["cmd", "--timestamps=millis"]).unwrap();
+        assert_eq!(cli.timestamps.timestamps(), Timestamps::Millis);
+        assert!(Cli::try_parse_from(["cmd", "--timestamps=hours"]).is_err());
+    }
+
+    #[test]
+    fn format() {
+        let time = Utc
+            .with_ymd_and_hms(2024, 5, 1, 12, 0, 0)
+            .unwrap()
+            .with_nanosecond(123_456_789)
+            .unwrap();
+        assert_eq!(Timestamps::None.format(time), None);
+        assert_eq!(
+            Timestamps::Seconds.format(time).unwrap(),
+            "2024-05-01T12:00:00Z"
+        );
+        assert_eq!(
+            Timestamps::Millis.format(time).unwrap(),
+            "2024-05-01T12:00:00.123Z"
+        );
+        assert_eq!(
+            Timestamps::Rfc3339.format(time).unwrap(),
+            "2024-05-01T12:00:00.123456Z"
+        );
+        assert!(Timestamps::Local.now().is_some());
+    }
+}
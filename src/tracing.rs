@@ -31,6 +31,13 @@ impl From<LevelFilter> for VerbosityFilter {
     }
 }
 
+/// See `From<VerbosityFilter> for Verbosity`
+impl<L: LogLevel> From<LevelFilter> for Verbosity<L> {
+    fn from(level: LevelFilter) -> Self {
+        VerbosityFilter::from(level).into()
+    }
+}
+
 impl From<VerbosityFilter> for Option<Level> {
     fn from(filter: VerbosityFilter) -> Self {
         match filter {
@@ -57,6 +64,12 @@ impl From<Option<Level>> for VerbosityFilter {
     }
 }
 
+impl From<Level> for VerbosityFilter {
+    fn from(level: Level) -> Self {
+        Some(level).into()
+    }
+}
+
 impl<L: LogLevel> From<Verbosity<L>> for LevelFilter {
     fn from(v: Verbosity<L>) -> Self {
         v.tracing_level_filter()
@@ -73,6 +86,7 @@ impl<L: LogLevel> From<Verbosity<L>> for Option<Level> {
 mod tests {
     use super::*;
     use crate::{DebugLevel, ErrorLevel, InfoLevel, OffLevel, TraceLevel, Verbosity, WarnLevel};
+    use crate::{ShortcutVerbosity, VerboseOnly, VerbosityFilter, VerbosityLevel};
 
     #[test]
     fn tracing_level() {
@@ -142,4 +156,42 @@ mod tests {
         let v = Verbosity::<TraceLevel>::default();
         assert_eq!(LevelFilter::from(v), LevelFilter::TRACE);
     }
+
+    #[test]
+    fn from_verbosity_level() {
+        let v = VerbosityLevel::<ErrorLevel>::new(VerbosityFilter::Off);
+        assert_eq!(Option::<Level>::from(v), None);
+        assert_eq!(LevelFilter::from(v), LevelFilter::OFF);
+
+        let v = VerbosityLevel::<ErrorLevel>::new(VerbosityFilter::Debug);
+        assert_eq!(Option::<Level>::from(v), Some(Level::DEBUG));
+        assert_eq!(LevelFilter::from(v), LevelFilter::DEBUG);
+    }
+
+    #[test]
+    fn from_wrappers() {
+        let v = VerboseOnly::<ErrorLevel>::new(2);
+        assert_eq!(Option::<Level>::from(v), Some(Level::INFO));
+        assert_eq!(LevelFilter::from(v), LevelFilter::INFO);
+        assert_eq!(VerbosityFilter::from(v), VerbosityFilter::Info);
+
+        let v = ShortcutVerbosity::<ErrorLevel>::new(Verbosity::new(0, 0), true, false);
+        assert_eq!(LevelFilter::from(v), LevelFilter::DEBUG);
+    }
+
+    #[test]
+    fn into_verbosity() {
+        let v = Verbosity::<ErrorLevel>::from(LevelFilter::DEBUG);
+        assert_eq!(v.verbose(), 3);
+        assert_eq!(LevelFilter::from(v), LevelFilter::DEBUG);
+    }
+
+    #[test]
+    fn is_enabled() {
+        assert!(VerbosityFilter::Warn.is_enabled(Level::ERROR));
+        assert!(VerbosityFilter::Warn.is_enabled(Level::WARN));
+        assert!(!VerbosityFilter::Warn.is_enabled(Level::TRACE));
+        assert!(!VerbosityFilter::Off.is_enabled(Level::ERROR));
+        assert!(VerbosityFilter::Trace.is_enabled(Level::TRACE));
+    }
 }
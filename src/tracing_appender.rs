@@ -0,0 +1,73 @@
+//! Log to files with [`tracing_appender`]
+
+use std::io;
+
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+use crate::{LogLevel, Verbosity};
+
+impl<L: LogLevel> Verbosity<L> {
+    /// A [`tracing_subscriber::fmt()`] layer writing to `writer` on a background thread, at the
+    /// flags' level
+    ///
+    /// Lines are written without colors.  Keep the guard alive until the end of `main` so
+    /// buffered lines are flushed, e.g. with a daily rotated file:
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// use tracing_subscriber::prelude::*;
+    ///
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// let appender = tracing_appender::rolling::daily("logs", "app.log");
+    /// let (layer, _guard) = cli.verbose.file_layer(appender);
+    /// tracing_subscriber::registry().with(layer).init();
+    /// ```
+    pub fn file_layer<S>(
+        &self,
+        writer: impl io::Write + Send + 'static,
+    ) -> (impl Layer<S>, WorkerGuard)
+    where
+        S: tracing_core::Subscriber + for<'a> LookupSpan<'a>,
+    {
+        let (writer, guard) = tracing_appender::non_blocking(writer);
+        let layer = tracing_subscriber::fmt::layer()
+            .with_ansi(false)
+            .with_writer(writer)
+            .with_filter(self.tracing_level_filter());
+        (layer, guard)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing_subscriber::prelude::*;
+
+    use super::*;
+    use crate::tracing_subscriber::TestWriter;
+    use crate::ErrorLevel;
+
+    #[test]
+    fn file_layer() {
+        let buffer = TestWriter::default();
+        let (layer, guard) = Verbosity::<ErrorLevel>::new(1, 0).file_layer(buffer.clone());
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!("shown");
+            tracing::info!("hidden");
+        });
+        drop(guard);
+
+        let contents = buffer.contents();
+        assert!(contents.contains("WARN"), "{contents}");
+        assert!(contents.contains("shown"), "{contents}");
+        assert!(!contents.contains("hidden"), "{contents}");
+    }
+}
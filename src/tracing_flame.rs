@@ -0,0 +1,85 @@
+//! Profile with [`tracing_flame`] at trace verbosity
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+use tracing_flame::{FlameLayer, FlushGuard};
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::{LogLevel, Verbosity, VerbosityFilter};
+
+/// The layer returned by [`Verbosity::flame_layer`]
+pub type FlameFileLayer<S> = FlameLayer<S, BufWriter<File>>;
+
+/// Flushes the folded stacks of a [`FlameFileLayer`] when dropped
+pub type FlameFileGuard = FlushGuard<BufWriter<File>>;
+
+impl<L: LogLevel> Verbosity<L> {
+    /// A layer writing folded stacks to `path`, if the flags are at [`VerbosityFilter::Trace`]
+    ///
+    /// Keep the guard alive until the end of `main` so the file is complete, then render it with
+    /// `inferno-flamegraph`:
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// use tracing_subscriber::prelude::*;
+    ///
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// let (flame, _guard) = cli.verbose.flame_layer("tracing.folded").unwrap().unzip();
+    /// tracing_subscriber::registry()
+    ///     .with(flame)
+    ///     .with(tracing_subscriber::fmt::layer().with_filter(cli.verbose.tracing_level_filter()))
+    ///     .init();
+    /// ```
+    ///
+    /// The file is only created when the layer is returned.
+    pub fn flame_layer<S>(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<Option<(FlameFileLayer<S>, FlameFileGuard)>, tracing_flame::Error>
+    where
+        S: tracing_core::Subscriber + for<'a> LookupSpan<'a>,
+    {
+        if self.filter() < VerbosityFilter::Trace {
+            return Ok(None);
+        }
+        FlameLayer::with_file(path).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing_subscriber::prelude::*;
+    use tracing_subscriber::Registry;
+
+    use super::*;
+    use crate::InfoLevel;
+
+    #[test]
+    fn flame_layer() {
+        let path =
+            std::env::temp_dir().join(format!("clap-verbosity-flag-{}.folded", std::process::id()));
+
+        let verbosity = Verbosity::<InfoLevel>::new(1, 0);
+        assert!(verbosity.flame_layer::<Registry>(&path).unwrap().is_none());
+        assert!(!path.exists());
+
+        let verbosity = Verbosity::<InfoLevel>::new(2, 0);
+        let (layer, guard) = verbosity.flame_layer(&path).unwrap().unwrap();
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            let _span = tracing::trace_span!("work").entered();
+        });
+        guard.flush().unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(contents.contains("work"), "{contents}");
+    }
+}
@@ -0,0 +1,81 @@
+//! Write [logfmt](https://brandur.org/logfmt) lines with [`tracing_logfmt`]
+
+use std::io;
+
+use tracing_core::LevelFilter;
+use tracing_logfmt::{EventsFormatter, FieldsFormatter};
+use tracing_subscriber::fmt::SubscriberBuilder;
+
+use crate::{LogLevel, Verbosity};
+
+/// The builder returned by [`Verbosity::logfmt_subscriber_builder`]
+pub type LogfmtSubscriberBuilder =
+    SubscriberBuilder<FieldsFormatter, EventsFormatter, LevelFilter, fn() -> io::Stderr>;
+
+impl<L: LogLevel> Verbosity<L> {
+    /// A subscriber builder writing logfmt lines to stderr at the flags' level
+    ///
+    /// Customize the builder further before calling [`SubscriberBuilder::init`], or use
+    /// [`Verbosity::init_logfmt`].
+    pub fn logfmt_subscriber_builder(&self) -> LogfmtSubscriberBuilder {
+        tracing_logfmt::builder()
+            .subscriber_builder()
+            .with_max_level(self.tracing_level_filter())
+            .with_writer(io::stderr as fn() -> io::Stderr)
+    }
+
+    /// Install [`Verbosity::logfmt_subscriber_builder`] as the global default subscriber
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose.init_logfmt();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If a global default subscriber was already set, see [`Verbosity::try_init_logfmt`].
+    pub fn init_logfmt(&self) {
+        self.logfmt_subscriber_builder().init();
+    }
+
+    /// Install [`Verbosity::logfmt_subscriber_builder`] as the global default subscriber, failing
+    /// if one already is
+    pub fn try_init_logfmt(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.logfmt_subscriber_builder().try_init()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing_subscriber::TestWriter;
+    use crate::ErrorLevel;
+
+    #[test]
+    fn logfmt_subscriber_builder() {
+        let buffer = TestWriter::default();
+        let subscriber = Verbosity::<ErrorLevel>::new(1, 0)
+            .logfmt_subscriber_builder()
+            .with_writer(buffer.clone())
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(code = 7, "shown");
+            tracing::info!("hidden");
+        });
+
+        let contents = buffer.contents();
+        assert!(contents.contains("level=warn"), "{contents}");
+        assert!(contents.contains("message=shown code=7"), "{contents}");
+        assert!(!contents.contains("hidden"), "{contents}");
+    }
+}
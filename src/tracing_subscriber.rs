@@ -0,0 +1,718 @@
+//! Helpers for [`tracing_subscriber`]
+
+use std::io;
+
+use tracing_core::{Level, LevelFilter, Metadata};
+use tracing_subscriber::fmt::format::{DefaultFields, Format};
+use tracing_subscriber::fmt::writer::EitherWriter;
+use tracing_subscriber::fmt::{MakeWriter, SubscriberBuilder};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+
+#[cfg(feature = "timestamps")]
+use crate::Timestamps;
+use crate::{ErrorLevel, LogLevel, Verbosity, VerbosityFilter};
+
+/// The [`tracing_subscriber::fmt()`] builder returned by [`Verbosity::tracing_subscriber_builder`]
+pub type StderrSubscriberBuilder =
+    SubscriberBuilder<DefaultFields, Format, LevelFilter, fn() -> io::Stderr>;
+
+impl<L: LogLevel> Verbosity<L> {
+    /// A [`tracing_subscriber::fmt()`] builder writing to stderr at the flags' level
+    ///
+    /// Customize the builder further before calling [`SubscriberBuilder::init`], or use
+    /// [`Verbosity::init_tracing`].
+    pub fn tracing_subscriber_builder(&self) -> StderrSubscriberBuilder {
+        tracing_subscriber::fmt()
+            .with_max_level(self.tracing_level_filter())
+            .with_writer(io::stderr as fn() -> io::Stderr)
+    }
+
+    /// Install [`Verbosity::tracing_subscriber_builder`] as the global default subscriber
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose.init_tracing();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If a global default subscriber was already set, see [`Verbosity::try_init_tracing`].
+    pub fn init_tracing(&self) {
+        self.tracing_subscriber_builder().init();
+    }
+
+    /// Install [`Verbosity::tracing_subscriber_builder`] as the global default subscriber, failing
+    /// if one already is
+    pub fn try_init_tracing(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.tracing_subscriber_builder().try_init()
+    }
+
+    /// Install [`Verbosity::tracing_subscriber_builder`] as the global default subscriber, with
+    /// warnings and errors on stderr and everything else on stdout
+    ///
+    /// See [`SplitWriter::stdio`].
+    ///
+    /// # Panics
+    ///
+    /// If a global default subscriber was already set, see [`Verbosity::try_init_tracing_split`].
+    pub fn init_tracing_split(&self) {
+        self.try_init_tracing_split()
+            .expect("failed to set global default subscriber");
+    }
+
+    /// Install [`Verbosity::tracing_subscriber_builder`] as the global default subscriber, with
+    /// warnings and errors on stderr and everything else on stdout, failing if one already is
+    pub fn try_init_tracing_split(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.tracing_subscriber_builder()
+            .with_writer(SplitWriter::stdio())
+            .try_init()
+    }
+}
+
+#[cfg(feature = "timestamps")]
+impl<L: LogLevel> Verbosity<L> {
+    /// Install [`Verbosity::tracing_subscriber_builder`] as the global default subscriber,
+    /// prefixing each line with the time in `timestamps`
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::{TimestampsArg, Verbosity};
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// #     #[command(flatten)]
+    /// #     timestamps: TimestampsArg,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose
+    ///     .init_tracing_with_timestamps(cli.timestamps.timestamps());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If a global default subscriber was already set, see
+    /// [`Verbosity::try_init_tracing_with_timestamps`].
+    pub fn init_tracing_with_timestamps(&self, timestamps: Timestamps) {
+        self.try_init_tracing_with_timestamps(timestamps)
+            .expect("failed to set global default subscriber");
+    }
+
+    /// Install [`Verbosity::tracing_subscriber_builder`] as the global default subscriber,
+    /// prefixing each line with the time in `timestamps`, failing if one already is
+    pub fn try_init_tracing_with_timestamps(
+        &self,
+        timestamps: Timestamps,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let builder = self.tracing_subscriber_builder();
+        if timestamps.is_enabled() {
+            builder.with_timer(timestamps).try_init()
+        } else {
+            builder.without_time().try_init()
+        }
+    }
+}
+
+/// Pass to `with_timer`; for [`Timestamps::None`], call `without_time` instead, as this would
+/// leave a blank
+#[cfg(feature = "timestamps")]
+impl tracing_subscriber::fmt::time::FormatTime for Timestamps {
+    fn format_time(&self, w: &mut tracing_subscriber::fmt::format::Writer<'_>) -> std::fmt::Result {
+        match self.now() {
+            Some(now) => w.write_str(&now),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "env-filter")]
+impl<L: LogLevel> Verbosity<L> {
+    /// An [`EnvFilter`][tracing_subscriber::EnvFilter] at the flags' level, refined by `RUST_LOG`
+    ///
+    /// Per-target directives, like `RUST_LOG=hyper=warn`, always apply.  A level without a
+    /// target, like `RUST_LOG=debug`, replaces the flags' level only when no flags were passed, see
+    /// [`Verbosity::is_present`].  So `-v` and `-q` always control the baseline, even with
+    /// `RUST_LOG` exported in the shell:
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// tracing_subscriber::fmt()
+    ///     .with_env_filter(cli.verbose.env_filter())
+    ///     .init();
+    /// ```
+    ///
+    /// Invalid directives are skipped.
+    pub fn env_filter(&self) -> tracing_subscriber::EnvFilter {
+        let directives = std::env::var(tracing_subscriber::EnvFilter::DEFAULT_ENV);
+        self.env_filter_with(&directives.unwrap_or_default())
+    }
+
+    /// [`Verbosity::env_filter`] with `directives` instead of `RUST_LOG`
+    pub fn env_filter_with(&self, directives: &str) -> tracing_subscriber::EnvFilter {
+        let filter = tracing_subscriber::EnvFilter::builder().parse_lossy(directives);
+        let has_level = directives
+            .split(',')
+            .map(str::trim)
+            .any(|directive| !directive.is_empty() && directive.parse::<LevelFilter>().is_ok());
+        if self.is_present() || !has_level {
+            filter.add_directive(self.tracing_level_filter().into())
+        } else {
+            filter
+        }
+    }
+}
+
+/// Attach the flags' level to a single layer, e.g. to keep a log file at its own level
+///
+/// ```rust,no_run
+/// # use clap::Parser;
+/// # use clap_verbosity_flag::Verbosity;
+/// use tracing_subscriber::prelude::*;
+///
+/// /// Le CLI
+/// #[derive(Debug, Parser)]
+/// struct Cli {
+///     #[command(flatten)]
+///     verbose: Verbosity,
+/// }
+///
+/// let cli = Cli::parse();
+/// tracing_subscriber::registry()
+///     .with(tracing_subscriber::fmt::layer().with_filter(cli.verbose))
+///     .init();
+/// ```
+impl<L: LogLevel, S> tracing_subscriber::layer::Filter<S> for Verbosity<L> {
+    fn enabled(&self, meta: &Metadata<'_>, _: &Context<'_, S>) -> bool {
+        self.tracing_level_filter() >= *meta.level()
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        Some(self.tracing_level_filter())
+    }
+}
+
+/// A [`MakeWriter`] sending warnings and errors to one writer and everything else to another
+///
+/// This only decides *where* a record goes; *whether* it is written is still up to the
+/// subscriber's level, e.g.
+/// ```rust,no_run
+/// # use clap::Parser;
+/// # use clap_verbosity_flag::Verbosity;
+/// # use clap_verbosity_flag::tracing_subscriber::SplitWriter;
+/// #
+/// # /// Le CLI
+/// # #[derive(Debug, Parser)]
+/// # struct Cli {
+/// #     #[command(flatten)]
+/// #     verbose: Verbosity,
+/// # }
+/// let cli = Cli::parse();
+/// tracing_subscriber::fmt()
+///     .with_max_level(cli.verbose)
+///     .with_writer(SplitWriter::stdio())
+///     .init();
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct SplitWriter<D, O> {
+    diagnostics: D,
+    output: O,
+}
+
+impl<D, O> SplitWriter<D, O> {
+    /// Route `WARN` and `ERROR` records to `diagnostics` and `INFO`, `DEBUG` and `TRACE` to `output`
+    pub fn new(diagnostics: D, output: O) -> Self {
+        Self {
+            diagnostics,
+            output,
+        }
+    }
+}
+
+impl SplitWriter<fn() -> io::Stderr, fn() -> io::Stdout> {
+    /// Keep diagnostics on stderr so they don't mix with the output consumed by pipelines
+    pub fn stdio() -> Self {
+        Self::new(io::stderr, io::stdout)
+    }
+}
+
+impl<'a, D, O> MakeWriter<'a> for SplitWriter<D, O>
+where
+    D: MakeWriter<'a>,
+    O: MakeWriter<'a>,
+{
+    type Writer = EitherWriter<D::Writer, O::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        EitherWriter::B(self.output.make_writer())
+    }
+
+    fn make_writer_for(&'a self, meta: &Metadata<'_>) -> Self::Writer {
+        if *meta.level() <= Level::WARN {
+            EitherWriter::A(self.diagnostics.make_writer_for(meta))
+        } else {
+            EitherWriter::B(self.output.make_writer_for(meta))
+        }
+    }
+}
+
+/// [`Verbosity`] plus `--verbose-scope <SPAN_OR_TARGET>` to focus it on part of the program
+///
+/// When any scopes are given, the `-v`/`-q` level only applies to events from a matching target
+/// (or a child module of it) or inside a span of that name; everything else stays at the
+/// `--log-profile` level, the [`Verbosity::set_default`] one or [`LogLevel::default_filter`].
+/// Attach [`ScopedVerbosity::layer_filter`] to a layer:
+/// ```rust,no_run
+/// # use clap::Parser;
+/// # use clap_verbosity_flag::tracing_subscriber::ScopedVerbosity;
+/// use tracing_subscriber::prelude::*;
+///
+/// /// Le CLI
+/// #[derive(Debug, Parser)]
+/// struct Cli {
+///     #[command(flatten)]
+///     verbose: ScopedVerbosity,
+/// }
+///
+/// let cli = Cli::parse();
+/// tracing_subscriber::registry()
+///     .with(tracing_subscriber::fmt::layer().with_filter(cli.verbose.layer_filter()))
+///     .init();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ScopedVerbosity<L: LogLevel = ErrorLevel> {
+    verbosity: Verbosity<L>,
+    scopes: Vec<String>,
+}
+
+impl<L: LogLevel> ScopedVerbosity<L> {
+    /// Create a new instance by explicitly setting the values
+    pub fn new(verbosity: Verbosity<L>, scopes: Vec<String>) -> Self {
+        Self { verbosity, scopes }
+    }
+
+    /// The `-v`/`-q` flags
+    pub fn verbosity(&self) -> &Verbosity<L> {
+        &self.verbosity
+    }
+
+    /// The spans and targets the verbosity is restricted to, if any
+    pub fn scopes(&self) -> &[String] {
+        &self.scopes
+    }
+
+    /// A per-layer filter applying the verbosity within the scopes
+    pub fn layer_filter(&self) -> ScopeFilter {
+        ScopeFilter {
+            inside: self.verbosity.filter().into(),
+            outside: self.outside().into(),
+            scopes: self.scopes.clone(),
+        }
+    }
+
+    /// The level outside of the scopes, the same as inside without any
+    pub(crate) fn outside(&self) -> VerbosityFilter {
+        if self.scopes.is_empty() {
+            self.verbosity.filter()
+        } else {
+            self.verbosity.outside_scopes()
+        }
+    }
+}
+
+impl<L: LogLevel> clap::FromArgMatches for ScopedVerbosity<L> {
+    fn from_arg_matches(matches: &clap::ArgMatches) -> Result<Self, clap::Error> {
+        let mut verbosity = Self::new(Verbosity::new(0, 0), Vec::new());
+        verbosity.update_from_arg_matches(matches)?;
+        Ok(verbosity)
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &clap::ArgMatches) -> Result<(), clap::Error> {
+        self.verbosity.update_from_arg_matches(matches)?;
+        if let Some(scopes) = matches.get_many::<String>(&crate::prefixed::<L>(SCOPE_ID)) {
+            self.scopes = scopes.cloned().collect();
+        }
+        Ok(())
+    }
+}
+
+impl<L: LogLevel> clap::Args for ScopedVerbosity<L> {
+    fn group_id() -> Option<clap::Id> {
+        <Verbosity<L> as clap::Args>::group_id()
+    }
+
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        let id = crate::prefixed::<L>(SCOPE_ID);
+        let scope = clap::Arg::new(id.clone())
+            .long(id)
+            .value_name("SPAN_OR_TARGET")
+            .action(clap::ArgAction::Append)
+            .global(L::global())
+            .help("Only change verbosity within this span or target");
+        <Verbosity<L> as clap::Args>::augment_args(cmd).arg(crate::help_placement::<L>(scope))
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_args(cmd)
+    }
+}
+
+const SCOPE_ID: &str = "verbose-scope";
+
+/// A [`tracing_subscriber::layer::Filter`] with a different level inside some spans and targets
+///
+/// See [`ScopedVerbosity::layer_filter`].
+#[derive(Clone, Debug)]
+pub struct ScopeFilter {
+    inside: LevelFilter,
+    outside: LevelFilter,
+    scopes: Vec<String>,
+}
+
+impl ScopeFilter {
+    fn matches_target(&self, target: &str) -> bool {
+        self.scopes.iter().any(|scope| {
+            target
+                .strip_prefix(scope.as_str())
+                .map(|rest| rest.is_empty() || rest.starts_with("::"))
+                .unwrap_or(false)
+        })
+    }
+}
+
+impl<S> tracing_subscriber::layer::Filter<S> for ScopeFilter
+where
+    S: tracing_core::Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn enabled(&self, meta: &Metadata<'_>, cx: &Context<'_, S>) -> bool {
+        // Scope spans must be enabled to be found as the parents of events
+        if meta.is_span() && self.scopes.iter().any(|scope| scope == meta.name()) {
+            return true;
+        }
+        if self.outside >= *meta.level() {
+            return true;
+        }
+        if self.inside < *meta.level() {
+            return false;
+        }
+        if self.matches_target(meta.target()) {
+            return true;
+        }
+        cx.lookup_current()
+            .map(|current| {
+                current.scope().any(|span| {
+                    self.scopes.iter().any(|scope| scope == span.name())
+                        || self.matches_target(span.metadata().target())
+                })
+            })
+            .unwrap_or(false)
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        if self.scopes.is_empty() {
+            Some(self.inside)
+        } else {
+            // Scope spans can be at any level
+            None
+        }
+    }
+}
+
+/// An in-memory writer for checking formatted output
+#[cfg(test)]
+#[derive(Clone, Default)]
+pub(crate) struct TestWriter(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+#[cfg(test)]
+impl TestWriter {
+    pub(crate) fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+#[cfg(test)]
+impl io::Write for TestWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+impl MakeWriter<'_> for TestWriter {
+    type Writer = Self;
+
+    fn make_writer(&self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InfoLevel, Verbosity};
+
+    #[test]
+    fn scoped_verbosity() {
+        use clap::Parser as _;
+        use tracing_subscriber::prelude::*;
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            verbose: ScopedVerbosity,
+        }
+
+        let parse = |args: &[&str]| Cli::try_parse_from(args).unwrap().verbose;
+        let capture = |verbose: ScopedVerbosity| {
+            let buffer = TestWriter::default();
+            let subscriber = tracing_subscriber::registry().with(
+                tracing_subscriber::fmt::layer()
+                    .with_ansi(false)
+                    .with_writer(buffer.clone())
+                    .with_filter(verbose.layer_filter()),
+            );
+            tracing::subscriber::with_default(subscriber, || {
+                tracing::error!("outside error");
+                tracing::info!("outside info");
+                tracing::info!(target: "db::pool", "target info");
+                tracing::info!(target: "dbx", "prefix info");
+                tracing::debug_span!("db").in_scope(|| {
+                    tracing::info!("span info");
+                    tracing::debug!("span debug");
+                    tracing::info_span!("query").in_scope(|| tracing::info!("nested info"));
+                });
+                tracing::info_span!(target: "hyper", "conn").in_scope(|| {
+                    tracing::error!("conn error");
+                });
+            });
+            buffer.contents()
+        };
+
+        let output = capture(parse(&["cmd", "-vv", "--verbose-scope", "db"]));
+        assert!(output.contains("outside error"), "{output}");
+        assert!(!output.contains("outside info"), "{output}");
+        assert!(output.contains("target info"), "{output}");
+        assert!(!output.contains("prefix info"), "{output}");
+        assert!(output.contains("span info"), "{output}");
+        assert!(!output.contains("span debug"), "{output}");
+        assert!(
+            output.contains("db:query: ") && output.contains("nested info"),
+            "{output}"
+        );
+        // Out-of-scope spans stay disabled, so they don't show up in the span context
+        assert!(output.contains("conn error"), "{output}");
+        assert!(!output.contains("conn:"), "{output}");
+
+        let output = capture(parse(&["cmd", "-vv"]));
+        assert!(output.contains("outside info"), "{output}");
+        assert!(output.contains("prefix info"), "{output}");
+
+        let output = capture(parse(&["cmd", "-q", "--verbose-scope", "db"]));
+        assert!(!output.contains("outside error"), "{output}");
+
+        // Outside the scopes, the runtime default still applies
+        let verbose = Verbosity::<ErrorLevel>::new(1, 0).with_default(VerbosityFilter::Info);
+        let filter = ScopedVerbosity::new(verbose, vec!["db".into()]).layer_filter();
+        assert_eq!(
+            (filter.inside, filter.outside),
+            (LevelFilter::DEBUG, LevelFilter::INFO)
+        );
+    }
+
+    #[test]
+    fn scoped_verbosity_prefix() {
+        use clap::{CommandFactory as _, Parser as _};
+
+        use crate::VerbosityFilter;
+
+        #[derive(Debug)]
+        struct Client;
+
+        impl LogLevel for Client {
+            fn default_filter() -> VerbosityFilter {
+                VerbosityFilter::Error
+            }
+
+            fn prefix() -> Option<&'static str> {
+                Some("client")
+            }
+
+            fn verbose_short() -> Option<char> {
+                None
+            }
+
+            fn quiet_short() -> Option<char> {
+                None
+            }
+
+            fn help_heading() -> Option<&'static str> {
+                Some("Client logging")
+            }
+        }
+
+        #[derive(Debug, clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            client: ScopedVerbosity<Client>,
+            #[command(flatten)]
+            server: ScopedVerbosity,
+        }
+
+        Cli::command().debug_assert();
+        let cmd = Cli::command();
+        let scope = cmd
+            .get_arguments()
+            .find(|arg| arg.get_id() == "client-verbose-scope")
+            .unwrap();
+        assert_eq!(scope.get_help_heading(), Some("Client logging"));
+
+        let cli = Cli::try_parse_from([
+            "cmd",
+            "--client-verbose",
+            "--client-verbose-scope",
+            "db",
+            "--verbose-scope",
+            "http",
+            "--verbose-scope",
+            "tls",
+        ])
+        .unwrap();
+        assert_eq!(cli.client.scopes(), ["db"]);
+        assert_eq!(cli.client.verbosity().filter(), VerbosityFilter::Warn);
+        assert_eq!(cli.server.scopes(), ["http", "tls"]);
+    }
+
+    #[test]
+    fn tracing_subscriber_builder() {
+        let buffer = TestWriter::default();
+        let subscriber = Verbosity::<InfoLevel>::new(0, 1)
+            .tracing_subscriber_builder()
+            .with_ansi(false)
+            .with_writer(buffer.clone())
+            .finish();
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!("smoking");
+            tracing::info!("exists");
+        });
+        let output = buffer.contents();
+        assert!(output.contains("smoking"), "{output}");
+        assert!(!output.contains("exists"), "{output}");
+    }
+
+    #[test]
+    #[cfg(feature = "env-filter")]
+    fn env_filter() {
+        let capture = |verbose: Verbosity<InfoLevel>, directives: &str| {
+            let buffer = TestWriter::default();
+            let subscriber = tracing_subscriber::fmt()
+                .with_env_filter(verbose.env_filter_with(directives))
+                .with_ansi(false)
+                .with_writer(buffer.clone())
+                .finish();
+            tracing::subscriber::with_default(subscriber, || {
+                tracing::info!("app info");
+                tracing::debug!("app debug");
+                tracing::debug!(target: "hyper", "hyper debug");
+            });
+            buffer.contents()
+        };
+
+        let output = capture(Verbosity::new(0, 0), "");
+        assert!(output.contains("app info"), "{output}");
+        assert!(!output.contains("debug"), "{output}");
+
+        let output = capture(Verbosity::new(0, 0), "hyper=debug");
+        assert!(output.contains("app info"), "{output}");
+        assert!(!output.contains("app debug"), "{output}");
+        assert!(output.contains("hyper debug"), "{output}");
+
+        let output = capture(Verbosity::new(0, 0), "debug");
+        assert!(output.contains("app debug"), "{output}");
+
+        let output = capture(Verbosity::new(0, 1), "debug,hyper=debug");
+        assert!(!output.contains("app info"), "{output}");
+        assert!(output.contains("hyper debug"), "{output}");
+    }
+
+    #[test]
+    fn layer_filter() {
+        use tracing_subscriber::prelude::*;
+
+        let quiet = TestWriter::default();
+        let verbose = TestWriter::default();
+        let subscriber = tracing_subscriber::registry()
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .with_ansi(false)
+                    .with_writer(quiet.clone())
+                    .with_filter(Verbosity::<InfoLevel>::new(0, 1)),
+            )
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .with_ansi(false)
+                    .with_writer(verbose.clone())
+                    .with_filter(Verbosity::<InfoLevel>::new(1, 0)),
+            );
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!("smoking");
+            tracing::debug!("temperature");
+        });
+
+        let quiet = quiet.contents();
+        assert!(quiet.contains("smoking"), "{quiet}");
+        assert!(!quiet.contains("temperature"), "{quiet}");
+        let verbose = verbose.contents();
+        assert!(verbose.contains("temperature"), "{verbose}");
+    }
+
+    #[test]
+    fn split_writer() {
+        let diagnostics = TestWriter::default();
+        let output = TestWriter::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(Verbosity::<InfoLevel>::default())
+            .with_ansi(false)
+            .with_writer(SplitWriter::new(diagnostics.clone(), output.clone()))
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::error!("exploded");
+            tracing::warn!("smoking");
+            tracing::info!("exists");
+            tracing::debug!("temperature");
+        });
+
+        let diagnostics = diagnostics.contents();
+        assert!(diagnostics.contains("exploded"), "{diagnostics}");
+        assert!(diagnostics.contains("smoking"), "{diagnostics}");
+        assert!(!diagnostics.contains("exists"), "{diagnostics}");
+
+        let output = output.contents();
+        assert!(output.contains("exists"), "{output}");
+        assert!(!output.contains("smoking"), "{output}");
+        assert!(!output.contains("temperature"), "{output}");
+    }
+}
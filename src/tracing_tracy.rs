@@ -0,0 +1,43 @@
+//! Profile with the [Tracy](https://github.com/wolfpld/tracy) profiler at debug verbosity
+
+pub use tracing_tracy::TracyLayer;
+
+use crate::{LogLevel, Verbosity, VerbosityFilter};
+
+impl<L: LogLevel> Verbosity<L> {
+    /// A [`TracyLayer`], if the flags are at [`VerbosityFilter::Debug`] or more
+    ///
+    /// Creating the layer starts the Tracy client, which the profiler GUI then connects to, so
+    /// nothing is started at lower verbosity:
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// use tracing_subscriber::prelude::*;
+    ///
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// tracing_subscriber::registry()
+    ///     .with(cli.verbose.tracy_layer())
+    ///     .with(tracing_subscriber::fmt::layer().with_filter(cli.verbose.tracing_level_filter()))
+    ///     .init();
+    /// ```
+    pub fn tracy_layer(&self) -> Option<TracyLayer> {
+        (self.filter() >= VerbosityFilter::Debug).then(TracyLayer::default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InfoLevel;
+
+    #[test]
+    fn tracy_layer() {
+        assert!(Verbosity::<InfoLevel>::new(0, 0).tracy_layer().is_none());
+    }
+}
@@ -0,0 +1,29 @@
+//! Log to the browser console with [`console_log`] when compiled to WebAssembly
+
+use crate::{LogLevel, Verbosity};
+
+impl<L: LogLevel> Verbosity<L> {
+    /// Install [`console_log`] as the global logger at the flags' level
+    ///
+    /// ```rust,no_run
+    /// # use clap::Parser;
+    /// # use clap_verbosity_flag::Verbosity;
+    /// #
+    /// # /// Le CLI
+    /// # #[derive(Debug, Parser)]
+    /// # struct Cli {
+    /// #     #[command(flatten)]
+    /// #     verbose: Verbosity,
+    /// # }
+    /// let cli = Cli::parse();
+    /// cli.verbose.init_wasm().unwrap();
+    /// ```
+    ///
+    /// Logging only works in a WebAssembly host with a `console`.
+    pub fn init_wasm(&self) -> Result<(), log::SetLoggerError> {
+        // `console_log` only takes a level, so `--quiet` down to off is applied afterwards
+        console_log::init_with_level(self.log_level().unwrap_or(log::Level::Error))?;
+        self.apply();
+        Ok(())
+    }
+}